@@ -28,7 +28,10 @@ use crate::{
 pub mod data_farmer;
 pub mod data_harvester;
 pub mod layout_manager;
+mod process_affinity;
+mod process_files;
 mod process_killer;
+mod process_oom;
 pub mod query;
 pub mod states;
 
@@ -62,6 +65,10 @@ pub struct AppConfigFields {
     pub no_write: bool,
     pub show_table_scroll_position: bool,
     pub is_advanced_kill: bool,
+    pub all_partitions: bool,
+    pub disk_full_warning: u64,
+    pub normalize_load_avg: bool,
+    pub network_packet_graph: bool,
     // TODO: Remove these, move network details state-side.
     pub network_unit_type: DataUnit,
     pub network_scale_type: AxisScaling,
@@ -73,6 +80,7 @@ pub struct AppConfigFields {
 pub struct DataFilters {
     pub disk_filter: Option<Filter>,
     pub mount_filter: Option<Filter>,
+    pub fs_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
     pub net_filter: Option<Filter>,
 }
@@ -97,6 +105,24 @@ pub struct App {
     #[builder(default, setter(skip))]
     to_delete_process_list: Option<(String, Vec<Pid>)>,
 
+    #[builder(default, setter(skip))]
+    pub affinity_err: Option<String>,
+
+    #[builder(default, setter(skip))]
+    to_set_affinity_process_list: Option<(String, Vec<Pid>)>,
+
+    #[builder(default, setter(skip))]
+    pub oom_err: Option<String>,
+
+    #[builder(default, setter(skip))]
+    to_adjust_oom_process_list: Option<(String, Vec<Pid>)>,
+
+    #[builder(default, setter(skip))]
+    pub files_err: Option<String>,
+
+    #[builder(default, setter(skip))]
+    to_show_files_process: Option<(String, Pid)>,
+
     #[builder(default = false, setter(skip))]
     pub is_frozen: bool,
 
@@ -112,6 +138,15 @@ pub struct App {
     #[builder(default, setter(skip))]
     pub delete_dialog_state: AppDeleteDialogState,
 
+    #[builder(default, setter(skip))]
+    pub affinity_dialog_state: AppAffinityDialogState,
+
+    #[builder(default, setter(skip))]
+    pub oom_dialog_state: AppOomDialogState,
+
+    #[builder(default, setter(skip))]
+    pub files_dialog_state: AppFilesDialogState,
+
     #[builder(default, setter(skip))]
     pub help_dialog_state: AppHelpDialogState,
 
@@ -144,6 +179,10 @@ pub struct App {
     pub temp_state: TempState,
     pub disk_state: DiskState,
     pub battery_state: BatteryState,
+    pub load_avg_state: LoadAvgState,
+    pub net_interfaces_state: NetInterfacesState,
+    pub protocols_state: ProtocolsState,
+    pub sockets_state: SocketsState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
     pub app_config_fields: AppConfigFields,
     pub widget_map: HashMap<u64, BottomWidget>,
@@ -169,6 +208,9 @@ impl App {
         // Reset dialog state
         self.help_dialog_state.is_showing_help = false;
         self.delete_dialog_state.is_showing_dd = false;
+        self.affinity_dialog_state.is_showing_affinity = false;
+        self.oom_dialog_state.is_showing_oom = false;
+        self.files_dialog_state.is_showing_files = false;
 
         // Close all searches and reset it
         self.proc_state
@@ -182,6 +224,12 @@ impl App {
         // Clear current delete list
         self.to_delete_process_list = None;
         self.dd_err = None;
+        self.to_set_affinity_process_list = None;
+        self.affinity_err = None;
+        self.to_adjust_oom_process_list = None;
+        self.oom_err = None;
+        self.to_show_files_process = None;
+        self.files_err = None;
 
         // Unfreeze.
         self.is_frozen = false;
@@ -203,16 +251,52 @@ impl App {
         self.delete_dialog_state.is_showing_dd = false;
         self.delete_dialog_state.selected_signal = KillSignal::default();
         self.delete_dialog_state.scroll_pos = 0;
+        self.delete_dialog_state.kill_target = KillTarget::default();
         self.to_delete_process_list = None;
         self.dd_err = None;
     }
 
+    fn close_affinity_dialog(&mut self) {
+        self.affinity_dialog_state.is_showing_affinity = false;
+        self.affinity_dialog_state.selected_cpus = vec![];
+        self.affinity_dialog_state.current_cpu = 0;
+        self.affinity_dialog_state.scroll_pos = 0;
+        self.to_set_affinity_process_list = None;
+        self.affinity_err = None;
+    }
+
+    fn close_oom_dialog(&mut self) {
+        self.oom_dialog_state.is_showing_oom = false;
+        self.oom_dialog_state.oom_score_adj = 0;
+        self.to_adjust_oom_process_list = None;
+        self.oom_err = None;
+    }
+
+    fn close_files_dialog(&mut self) {
+        self.files_dialog_state = AppFilesDialogState::default();
+        self.to_show_files_process = None;
+        self.files_err = None;
+    }
+
+    /// Changes the pending `oom_score_adj` in the OOM dialog, staying within the valid range.
+    fn change_oom_score_adj(&mut self, change: i32) {
+        self.oom_dialog_state.oom_score_adj = process_oom::clamp_oom_score_adj(
+            self.oom_dialog_state.oom_score_adj.saturating_add(change),
+        );
+    }
+
     pub fn on_esc(&mut self) {
         self.reset_multi_tap_keys();
         if self.is_in_dialog() {
             if self.help_dialog_state.is_showing_help {
                 self.help_dialog_state.is_showing_help = false;
                 self.help_dialog_state.scroll_state.current_scroll_index = 0;
+            } else if self.affinity_dialog_state.is_showing_affinity {
+                self.close_affinity_dialog();
+            } else if self.oom_dialog_state.is_showing_oom {
+                self.close_oom_dialog();
+            } else if self.files_dialog_state.is_showing_files {
+                self.close_files_dialog();
             } else {
                 self.close_dd();
             }
@@ -293,7 +377,11 @@ impl App {
     }
 
     fn is_in_dialog(&self) -> bool {
-        self.help_dialog_state.is_showing_help || self.delete_dialog_state.is_showing_dd
+        self.help_dialog_state.is_showing_help
+            || self.delete_dialog_state.is_showing_dd
+            || self.affinity_dialog_state.is_showing_affinity
+            || self.oom_dialog_state.is_showing_oom
+            || self.files_dialog_state.is_showing_files
     }
 
     fn ignore_normal_keybinds(&self) -> bool {
@@ -323,6 +411,9 @@ impl App {
                         if !proc_widget_state.is_tree_mode {
                             // Toggles process widget grouping state
                             proc_widget_state.is_grouped = !(proc_widget_state.is_grouped);
+                            if !proc_widget_state.is_grouped {
+                                proc_widget_state.is_grouped_by_unit = false;
+                            }
 
                             // Forcefully switch off column if we were on it...
                             if (proc_widget_state.is_grouped
@@ -373,6 +464,36 @@ impl App {
         }
     }
 
+    /// Toggles grouping processes by systemd unit.  If the widget is already grouped by name,
+    /// this just switches the grouping key over to the unit.
+    pub fn toggle_unit_grouping(&mut self) {
+        if let Some(proc_widget_state) = self
+            .proc_state
+            .get_mut_widget_state(self.current_widget.widget_id)
+        {
+            if proc_widget_state.is_tree_mode {
+                return;
+            }
+
+            if proc_widget_state.is_grouped && !proc_widget_state.is_grouped_by_unit {
+                proc_widget_state.is_grouped_by_unit = true;
+                proc_widget_state.requires_redraw = true;
+                self.proc_state.force_update = Some(self.current_widget.widget_id);
+                return;
+            }
+
+            let was_grouped = proc_widget_state.is_grouped;
+            self.on_tab();
+
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                proc_widget_state.is_grouped_by_unit = !was_grouped;
+            }
+        }
+    }
+
     /// I don't like this, but removing it causes a bunch of breakage.
     /// Use ``proc_widget_state.is_grouped`` if possible!
     pub fn is_grouped(&self, widget_id: u64) -> bool {
@@ -383,6 +504,15 @@ impl App {
         }
     }
 
+    /// Whether the process widget is grouping by systemd unit rather than by name.
+    pub fn is_grouped_by_unit(&self, widget_id: u64) -> bool {
+        if let Some(proc_widget_state) = self.proc_state.widget_states.get(&widget_id) {
+            proc_widget_state.is_grouped && proc_widget_state.is_grouped_by_unit
+        } else {
+            false
+        }
+    }
+
     pub fn on_slash(&mut self) {
         if !self.ignore_normal_keybinds() {
             match &self.current_widget.widget_type {
@@ -677,7 +807,27 @@ impl App {
     }
 
     pub fn toggle_tree_mode(&mut self) {
-        if let Some(proc_widget_state) = self
+        if let BottomWidgetType::Disk = self.current_widget.widget_type {
+            let widget_id = self.current_widget.widget_id;
+            if let Some(disk_widget_state) = self.disk_state.get_mut_widget_state(widget_id) {
+                disk_widget_state.is_tree_mode = !disk_widget_state.is_tree_mode;
+
+                let (disk_data, disk_full_warnings) = crate::data_conversion::convert_disk_row(
+                    &self.data_collection,
+                    disk_widget_state.is_tree_mode,
+                    self.app_config_fields.disk_full_warning,
+                );
+                disk_widget_state.scroll_state.current_scroll_position = min(
+                    disk_widget_state.scroll_state.current_scroll_position,
+                    disk_data.len().saturating_sub(1),
+                );
+                self.canvas_data.disk_data.insert(widget_id, disk_data);
+                self.canvas_data
+                    .disk_full_warnings
+                    .insert(widget_id, disk_full_warnings);
+            }
+            self.is_force_redraw = true;
+        } else if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
             .get_mut(&(self.current_widget.widget_id))
@@ -688,6 +838,7 @@ impl App {
             if proc_widget_state.is_tree_mode {
                 // Disable grouping if so!
                 proc_widget_state.is_grouped = false;
+                proc_widget_state.is_grouped_by_unit = false;
 
                 proc_widget_state
                     .columns
@@ -718,7 +869,30 @@ impl App {
 
     /// One of two functions allowed to run while in a dialog...
     pub fn on_enter(&mut self) {
-        if self.delete_dialog_state.is_showing_dd {
+        if self.affinity_dialog_state.is_showing_affinity {
+            if self.affinity_err.is_some() {
+                self.close_affinity_dialog();
+            } else if let Err(affinity_err) = self.set_affinity_highlighted_process() {
+                // Check if there was an issue... if so, inform the user.
+                self.affinity_err = Some(affinity_err.to_string());
+            } else {
+                self.close_affinity_dialog();
+            }
+            self.is_force_redraw = true;
+        } else if self.oom_dialog_state.is_showing_oom {
+            if self.oom_err.is_some() {
+                self.close_oom_dialog();
+            } else if let Err(oom_err) = self.adjust_oom_highlighted_process() {
+                // Check if there was an issue... if so, inform the user.
+                self.oom_err = Some(oom_err.to_string());
+            } else {
+                self.close_oom_dialog();
+            }
+            self.is_force_redraw = true;
+        } else if self.files_dialog_state.is_showing_files {
+            self.close_files_dialog();
+            self.is_force_redraw = true;
+        } else if self.delete_dialog_state.is_showing_dd {
             if self.dd_err.is_some() {
                 self.close_dd();
             } else if self.delete_dialog_state.selected_signal != KillSignal::Cancel {
@@ -812,7 +986,11 @@ impl App {
     }
 
     pub fn on_backspace(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
+        if self.files_dialog_state.is_showing_files {
+            if self.files_dialog_state.search_query.pop().is_some() {
+                self.files_dialog_state.current_index = 0;
+            }
+        } else if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             let is_in_search_widget = self.is_in_search_widget();
             if let Some(proc_widget_state) = self
                 .proc_state
@@ -915,6 +1093,14 @@ impl App {
             self.decrement_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.current_cpu =
+                self.affinity_dialog_state.current_cpu.saturating_sub(1);
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.change_oom_score_adj(100);
+        } else if self.files_dialog_state.is_showing_files {
+            self.files_dialog_state.current_index =
+                self.files_dialog_state.current_index.saturating_sub(1);
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_right_key();
@@ -937,6 +1123,20 @@ impl App {
             self.increment_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            if self.affinity_dialog_state.current_cpu + 1
+                < self.affinity_dialog_state.selected_cpus.len()
+            {
+                self.affinity_dialog_state.current_cpu += 1;
+            }
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.change_oom_score_adj(-100);
+        } else if self.files_dialog_state.is_showing_files {
+            if self.files_dialog_state.current_index + 1
+                < self.files_dialog_state.get_filtered_files().len()
+            {
+                self.files_dialog_state.current_index += 1;
+            }
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_left_key();
@@ -1022,6 +1222,8 @@ impl App {
             {
                 self.delete_dialog_state.selected_signal = KillSignal::Kill(1);
             }
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.change_oom_score_adj(-10);
         }
     }
 
@@ -1098,6 +1300,8 @@ impl App {
             {
                 self.delete_dialog_state.selected_signal = KillSignal::Cancel;
             }
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.change_oom_score_adj(10);
         }
     }
 
@@ -1243,7 +1447,10 @@ impl App {
     }
 
     pub fn clear_search(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
+        if self.files_dialog_state.is_showing_files {
+            self.files_dialog_state.search_query.clear();
+            self.files_dialog_state.current_index = 0;
+        } else if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             if let Some(proc_widget_state) = self
                 .proc_state
                 .widget_states
@@ -1331,9 +1538,8 @@ impl App {
         }
     }
 
-    pub fn start_killing_process(&mut self) {
-        self.reset_multi_tap_keys();
-
+    /// Returns the name and PIDs of the highlighted process (or group) in the current process widget.
+    fn get_highlighted_process(&self) -> Option<(String, Vec<Pid>)> {
         if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
@@ -1344,29 +1550,157 @@ impl App {
                 .finalized_process_data_map
                 .get(&self.current_widget.widget_id)
             {
-                if proc_widget_state.scroll_state.current_scroll_position
-                    < corresponding_filtered_process_list.len()
+                if let Some(process) = corresponding_filtered_process_list
+                    .get(proc_widget_state.scroll_state.current_scroll_position)
                 {
-                    let current_process: (String, Vec<Pid>);
-                    if self.is_grouped(self.current_widget.widget_id) {
-                        if let Some(process) = &corresponding_filtered_process_list
-                            .get(proc_widget_state.scroll_state.current_scroll_position)
-                        {
-                            current_process = (process.name.to_string(), process.group_pids.clone())
-                        } else {
-                            return;
-                        }
+                    return if self.is_grouped(self.current_widget.widget_id) {
+                        Some((process.name.to_string(), process.group_pids.clone()))
                     } else {
-                        let process = corresponding_filtered_process_list
-                            [proc_widget_state.scroll_state.current_scroll_position]
-                            .clone();
-                        current_process = (process.name.clone(), vec![process.pid])
+                        Some((process.name.clone(), vec![process.pid]))
                     };
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn start_killing_process(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some(current_process) = self.get_highlighted_process() {
+            self.to_delete_process_list = Some(current_process);
+            self.delete_dialog_state.is_showing_dd = true;
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    /// Sends a signal to the selected process (or group) without going through the kill dialog.  The
+    /// kill dialog is only shown if this fails, to report the error.
+    ///
+    /// Descendants are never signalled here, even in tree mode, as there's no chance to confirm
+    /// which processes that would hit; that's left to the kill dialog.
+    #[cfg(target_family = "unix")]
+    fn signal_highlighted_process(&mut self, signal: usize) {
+        self.reset_multi_tap_keys();
+
+        if let Some(current_process) = self.get_highlighted_process() {
+            self.to_delete_process_list = Some(current_process);
+            self.delete_dialog_state.selected_signal = KillSignal::Kill(signal);
+            self.delete_dialog_state.kill_target = KillTarget::Process;
+
+            if let Err(dd_err) = self.kill_highlighted_process() {
+                self.dd_err = Some(dd_err.to_string());
+                self.delete_dialog_state.is_showing_dd = true;
+                self.is_determining_widget_boundary = true;
+            } else {
+                self.close_dd();
+            }
+        }
+    }
+
+    #[cfg(target_family = "unix")]
+    pub fn suspend_highlighted_process(&mut self) {
+        self.signal_highlighted_process(libc::SIGSTOP as usize);
+    }
+
+    #[cfg(target_family = "unix")]
+    pub fn resume_highlighted_process(&mut self) {
+        self.signal_highlighted_process(libc::SIGCONT as usize);
+    }
+
+    /// Resumes the selected process (or group) if it is stopped, and otherwise suspends it.  A group
+    /// counts as stopped if every process in it is.
+    #[cfg(target_family = "unix")]
+    pub fn toggle_suspend_highlighted_process(&mut self) {
+        let is_stopped = self
+            .proc_state
+            .widget_states
+            .get(&self.current_widget.widget_id)
+            .and_then(|proc_widget_state| {
+                self.canvas_data
+                    .finalized_process_data_map
+                    .get(&self.current_widget.widget_id)
+                    .and_then(|processes| {
+                        processes.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+            })
+            .map_or(false, |process| process.process_char == 'T');
+
+        if is_stopped {
+            self.resume_highlighted_process();
+        } else {
+            self.suspend_highlighted_process();
+        }
+    }
+
+    pub fn start_setting_affinity(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some(current_process) = self.get_highlighted_process() {
+            // Start from the current affinity of the first process.
+            if let Some(first_pid) = current_process.1.first() {
+                match process_affinity::get_process_affinity(*first_pid) {
+                    Ok(selected_cpus) => {
+                        self.affinity_dialog_state.selected_cpus = selected_cpus;
+                    }
+                    Err(affinity_err) => {
+                        self.affinity_err = Some(affinity_err.to_string());
+                    }
+                }
+            }
+
+            self.to_set_affinity_process_list = Some(current_process);
+            self.affinity_dialog_state.current_cpu = 0;
+            self.affinity_dialog_state.scroll_pos = 0;
+            self.affinity_dialog_state.is_showing_affinity = true;
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    pub fn start_adjusting_oom(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let Some(current_process) = self.get_highlighted_process() {
+            // Start from the current adjustment of the first process.
+            if let Some(first_pid) = current_process.1.first() {
+                match process_oom::get_oom_score_adj(*first_pid) {
+                    Ok(oom_score_adj) => {
+                        self.oom_dialog_state.oom_score_adj = oom_score_adj;
+                    }
+                    Err(oom_err) => {
+                        self.oom_err = Some(oom_err.to_string());
+                    }
+                }
+            }
+
+            self.to_adjust_oom_process_list = Some(current_process);
+            self.oom_dialog_state.is_showing_oom = true;
+            self.is_determining_widget_boundary = true;
+        }
+    }
+
+    pub fn start_showing_files(&mut self) {
+        self.reset_multi_tap_keys();
 
-                    self.to_delete_process_list = Some(current_process);
-                    self.delete_dialog_state.is_showing_dd = true;
-                    self.is_determining_widget_boundary = true;
+        if let Some((name, pids)) = self.get_highlighted_process() {
+            // Grouped processes are shown by their first PID.
+            if let Some(first_pid) = pids.first() {
+                match process_files::get_open_files(*first_pid) {
+                    Ok(files) => {
+                        self.files_dialog_state.files = files;
+                    }
+                    Err(files_err) => {
+                        self.files_err = Some(files_err.to_string());
+                    }
                 }
+
+                self.to_show_files_process = Some((name, *first_pid));
+                self.files_dialog_state.search_query.clear();
+                self.files_dialog_state.current_index = 0;
+                self.files_dialog_state.scroll_pos = 0;
+                self.files_dialog_state.is_showing_files = true;
+                self.is_determining_widget_boundary = true;
             }
         }
     }
@@ -1471,6 +1805,66 @@ impl App {
                 '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                     self.on_number(caught_char)
                 }
+                't' => {
+                    let is_tree_mode = self
+                        .proc_state
+                        .widget_states
+                        .get(&self.current_widget.widget_id)
+                        .map_or(false, |proc_widget_state| proc_widget_state.is_tree_mode);
+                    self.delete_dialog_state.kill_target =
+                        self.delete_dialog_state.kill_target.next(is_tree_mode);
+                }
+                'g' => {
+                    let mut is_first_g = true;
+                    if let Some(second_char) = self.second_char {
+                        if self.awaiting_second_char && second_char == 'g' {
+                            is_first_g = false;
+                            self.awaiting_second_char = false;
+                            self.second_char = None;
+                            self.skip_to_first();
+                        }
+                    }
+
+                    if is_first_g {
+                        self.awaiting_second_char = true;
+                        self.second_char = Some('g');
+                    }
+                }
+                'G' => self.skip_to_last(),
+                _ => {}
+            }
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            match caught_char {
+                'j' => self.on_down_key(),
+                'k' => self.on_up_key(),
+                ' ' => self.affinity_dialog_state.toggle_current_cpu(),
+                'a' => self.affinity_dialog_state.toggle_all_cpus(),
+                'g' => {
+                    let mut is_first_g = true;
+                    if let Some(second_char) = self.second_char {
+                        if self.awaiting_second_char && second_char == 'g' {
+                            is_first_g = false;
+                            self.awaiting_second_char = false;
+                            self.second_char = None;
+                            self.skip_to_first();
+                        }
+                    }
+
+                    if is_first_g {
+                        self.awaiting_second_char = true;
+                        self.second_char = Some('g');
+                    }
+                }
+                'G' => self.skip_to_last(),
+                _ => {}
+            }
+        } else if self.oom_dialog_state.is_showing_oom {
+            match caught_char {
+                'h' => self.on_left_key(),
+                'j' => self.on_down_key(),
+                'k' => self.on_up_key(),
+                'l' => self.on_right_key(),
+                '0' => self.oom_dialog_state.oom_score_adj = 0,
                 'g' => {
                     let mut is_first_g = true;
                     if let Some(second_char) = self.second_char {
@@ -1490,6 +1884,14 @@ impl App {
                 'G' => self.skip_to_last(),
                 _ => {}
             }
+        } else if self.files_dialog_state.is_showing_files {
+            if self.files_err.is_none()
+                && UnicodeWidthStr::width(self.files_dialog_state.search_query.as_str())
+                    < MAX_SEARCH_LENGTH
+            {
+                self.files_dialog_state.search_query.push(caught_char);
+                self.files_dialog_state.current_index = 0;
+            }
         } else if self.is_config_open {
         }
     }
@@ -1546,6 +1948,96 @@ impl App {
             'C' => {
                 // self.open_config(),
             }
+            'a' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.start_setting_affinity();
+                }
+            }
+            'o' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.start_adjusting_oom();
+                }
+            }
+            'F' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.start_showing_files();
+                }
+            }
+            #[cfg(target_family = "unix")]
+            'x' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.suspend_highlighted_process();
+                }
+            }
+            #[cfg(target_family = "unix")]
+            'X' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.resume_highlighted_process();
+                }
+            }
+            'u' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    self.toggle_unit_grouping();
+                }
+            }
+            'z' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    if let Some(proc_widget_state) = self
+                        .proc_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        proc_widget_state.is_stuck_filter_enabled =
+                            !proc_widget_state.is_stuck_filter_enabled;
+                        proc_widget_state.scroll_state.current_scroll_position = 0;
+                        proc_widget_state.requires_redraw = true;
+                        self.proc_state.force_update = Some(self.current_widget.widget_id);
+                    }
+                }
+            }
+            'T' => {
+                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                    if let Some(proc_widget_state) = self
+                        .proc_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        proc_widget_state.is_kernel_threads_hidden =
+                            !proc_widget_state.is_kernel_threads_hidden;
+                        proc_widget_state.requires_redraw = true;
+                        self.proc_state.force_update = Some(self.current_widget.widget_id);
+                    }
+                }
+            }
+            'v' => match self.current_widget.widget_type {
+                BottomWidgetType::Battery => {
+                    if let Some(battery_widget_state) = self
+                        .battery_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        battery_widget_state.is_graph_mode = !battery_widget_state.is_graph_mode;
+                        self.is_force_redraw = true;
+                    }
+                }
+                BottomWidgetType::Protocols => {
+                    if let Some(protocols_widget_state) = self
+                        .protocols_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        protocols_widget_state.is_graph_shown =
+                            !protocols_widget_state.is_graph_shown;
+                        self.is_force_redraw = true;
+                    }
+                }
+                BottomWidgetType::Proc => {
+                    if let Some(proc_widget_state) = self
+                        .proc_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        proc_widget_state.is_io_graph_shown = !proc_widget_state.is_io_graph_shown;
+                        self.is_force_redraw = true;
+                    }
+                }
+                _ => {}
+            },
             'c' => {
                 if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
@@ -1709,21 +2201,45 @@ impl App {
     pub fn kill_highlighted_process(&mut self) -> Result<()> {
         if let BottomWidgetType::Proc = self.current_widget.widget_type {
             if let Some(current_selected_processes) = &self.to_delete_process_list {
-                #[cfg(target_family = "unix")]
+                let pids = match self.delete_dialog_state.kill_target {
+                    KillTarget::Process => current_selected_processes.1.clone(),
+                    KillTarget::Subtree => self.get_to_delete_subtree(),
+                    #[cfg(target_family = "unix")]
+                    KillTarget::ProcessGroup | KillTarget::Session => {
+                        let process_groups = self.get_to_delete_process_groups();
+                        if process_groups.is_empty() {
+                            return Err(BottomError::GenericError(
+                                "Could not find a process group to signal.".to_string(),
+                            ));
+                        }
+
+                        // A negative PID signals every process in that process group.
+                        process_groups.iter().map(|pgid| -pgid).collect()
+                    }
+                };
+
+                #[cfg(target_family = "unix")]
                 let signal = match self.delete_dialog_state.selected_signal {
                     KillSignal::Kill(sig) => sig,
                     KillSignal::Cancel => 15, // should never happen, so just TERM
                 };
-                for pid in &current_selected_processes.1 {
+
+                // Keep going if one fails, as some of the processes may exit while we're signalling
+                // the rest (especially when taking down a subtree), and report the first error.
+                let mut first_err = None;
+                for pid in pids {
                     #[cfg(target_family = "unix")]
-                    {
-                        process_killer::kill_process_given_pid(*pid, signal)?;
-                    }
+                    let result = process_killer::kill_process_given_pid(pid, signal);
                     #[cfg(target_os = "windows")]
-                    {
-                        process_killer::kill_process_given_pid(*pid)?;
+                    let result = process_killer::kill_process_given_pid(pid);
+
+                    if let Err(err) = result {
+                        first_err.get_or_insert(err);
                     }
                 }
+                if let Some(err) = first_err {
+                    return Err(err);
+                }
             }
             self.to_delete_process_list = None;
             Ok(())
@@ -1739,6 +2255,129 @@ impl App {
         self.to_delete_process_list.clone()
     }
 
+    /// Returns the selected process(es) and all of their descendants, following the parent links
+    /// of the latest harvest.  Children are ordered before their parents, so that signalling them
+    /// in order goes bottom-up and nothing gets reparented midway.  bottom itself and its ancestors
+    /// (i.e. the shell, terminal, or sshd it runs under) are never included.
+    pub fn get_to_delete_subtree(&self) -> Vec<Pid> {
+        let to_delete_pids = match &self.to_delete_process_list {
+            Some((_name, pids)) => pids,
+            None => return vec![],
+        };
+
+        process_killer::get_process_subtree(
+            to_delete_pids,
+            self.data_collection
+                .process_harvest
+                .iter()
+                .map(|process| (process.pid, process.parent_pid)),
+            std::process::id() as Pid,
+        )
+    }
+
+    /// Returns the process groups that the kill dialog will signal for its current target, which is
+    /// empty if only the processes themselves are targeted.  Our own process group is never
+    /// included, nor are those of the kernel and init.
+    #[cfg(target_family = "unix")]
+    pub fn get_to_delete_process_groups(&self) -> Vec<Pid> {
+        let to_delete_pids = match &self.to_delete_process_list {
+            Some((_name, pids)) => pids,
+            None => return vec![],
+        };
+        let process_harvest = &self.data_collection.process_harvest;
+        let to_delete_processes = process_harvest
+            .iter()
+            .filter(|process| to_delete_pids.contains(&process.pid));
+
+        let mut process_groups = match self.delete_dialog_state.kill_target {
+            KillTarget::Process | KillTarget::Subtree => vec![],
+            KillTarget::ProcessGroup => to_delete_processes
+                .filter_map(|process| process.process_group_id)
+                .collect(),
+            KillTarget::Session => {
+                let sessions = to_delete_processes
+                    .filter_map(|process| process.session_id)
+                    .collect::<Vec<_>>();
+                process_harvest
+                    .iter()
+                    .filter(|process| {
+                        process
+                            .session_id
+                            .map_or(false, |session_id| sessions.contains(&session_id))
+                    })
+                    .filter_map(|process| process.process_group_id)
+                    .collect()
+            }
+        };
+
+        let own_process_group = unsafe { libc::getpgrp() };
+        process_groups.sort_unstable();
+        process_groups.dedup();
+        process_groups.retain(|pgid| *pgid > 1 && *pgid != own_process_group);
+
+        process_groups
+    }
+
+    pub fn set_affinity_highlighted_process(&mut self) -> Result<()> {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if !self
+                .affinity_dialog_state
+                .selected_cpus
+                .iter()
+                .any(|selected| *selected)
+            {
+                return Err(BottomError::GenericError(
+                    "At least one CPU must be selected.".to_string(),
+                ));
+            }
+
+            if let Some(current_selected_processes) = &self.to_set_affinity_process_list {
+                for pid in &current_selected_processes.1 {
+                    process_affinity::set_process_affinity(
+                        *pid,
+                        &self.affinity_dialog_state.selected_cpus,
+                    )?;
+                }
+            }
+            self.to_set_affinity_process_list = None;
+            Ok(())
+        } else {
+            Err(BottomError::GenericError(
+                "Cannot set the affinity of processes if the current widget is not the Process widget!"
+                    .to_string(),
+            ))
+        }
+    }
+
+    pub fn get_to_set_affinity_processes(&self) -> Option<(String, Vec<Pid>)> {
+        self.to_set_affinity_process_list.clone()
+    }
+
+    pub fn adjust_oom_highlighted_process(&mut self) -> Result<()> {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(current_selected_processes) = &self.to_adjust_oom_process_list {
+                for pid in &current_selected_processes.1 {
+                    process_oom::set_oom_score_adj(*pid, self.oom_dialog_state.oom_score_adj)?;
+                }
+            }
+            self.to_adjust_oom_process_list = None;
+            Ok(())
+        } else {
+            Err(BottomError::GenericError(
+                "Cannot adjust the OOM score of processes if the current widget is not the Process widget!"
+                    .to_string(),
+            ))
+        }
+    }
+
+    pub fn get_to_adjust_oom_processes(&self) -> Option<(String, Vec<Pid>)> {
+        self.to_adjust_oom_process_list.clone()
+    }
+
+    pub fn get_to_show_files_process(&self) -> Option<(String, Pid)> {
+        self.to_show_files_process.clone()
+    }
+
     fn toggle_expand_widget(&mut self) {
         if self.is_expanded {
             self.is_expanded = false;
@@ -2242,6 +2881,36 @@ impl App {
                         temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
+                BottomWidgetType::NetInterfaces => {
+                    if let Some(net_interfaces_widget_state) = self
+                        .net_interfaces_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        net_interfaces_widget_state
+                            .scroll_state
+                            .current_scroll_position = 0;
+                        net_interfaces_widget_state.scroll_state.scroll_direction =
+                            ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::Sockets => {
+                    if let Some(sockets_widget_state) = self
+                        .sockets_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        sockets_widget_state.scroll_state.current_scroll_position = 0;
+                        sockets_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::Protocols => {
+                    if let Some(protocols_widget_state) = self
+                        .protocols_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        protocols_widget_state.scroll_state.current_scroll_position = 0;
+                        protocols_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
@@ -2267,6 +2936,12 @@ impl App {
         } else if self.is_config_open {
         } else if self.help_dialog_state.is_showing_help {
             self.help_dialog_state.scroll_state.current_scroll_index = 0;
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.current_cpu = 0;
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.oom_dialog_state.oom_score_adj = process_oom::MIN_OOM_SCORE_ADJ;
+        } else if self.files_dialog_state.is_showing_files {
+            self.files_dialog_state.current_index = 0;
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Cancel;
         }
@@ -2316,14 +2991,59 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::NetInterfaces => {
+                    if let Some(net_interfaces_widget_state) = self
+                        .net_interfaces_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.canvas_data.interface_data.is_empty() {
+                            net_interfaces_widget_state
+                                .scroll_state
+                                .current_scroll_position =
+                                self.canvas_data.interface_data.len() - 1;
+                            net_interfaces_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
+                BottomWidgetType::Sockets => {
+                    if let Some(sockets_widget_state) = self
+                        .sockets_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.canvas_data.socket_data.is_empty() {
+                            sockets_widget_state.scroll_state.current_scroll_position =
+                                self.canvas_data.socket_data.len() - 1;
+                            sockets_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
+                BottomWidgetType::Protocols => {
+                    if let Some(protocols_widget_state) = self
+                        .protocols_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.canvas_data.protocol_data.is_empty() {
+                            protocols_widget_state.scroll_state.current_scroll_position =
+                                self.canvas_data.protocol_data.len() - 1;
+                            protocols_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
                         .get_mut_widget_state(self.current_widget.widget_id)
                     {
-                        if !self.canvas_data.disk_data.is_empty() {
-                            disk_widget_state.scroll_state.current_scroll_position =
-                                self.canvas_data.disk_data.len() - 1;
+                        let num_disks = self
+                            .canvas_data
+                            .disk_data
+                            .get(&self.current_widget.widget_id)
+                            .map_or(0, |disk_data| disk_data.len());
+                        if num_disks > 0 {
+                            disk_widget_state.scroll_state.current_scroll_position = num_disks - 1;
                             disk_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
                         }
                     }
@@ -2350,6 +3070,20 @@ impl App {
                 .scroll_state
                 .max_scroll_index
                 .saturating_sub(1);
+        } else if self.affinity_dialog_state.is_showing_affinity {
+            self.affinity_dialog_state.current_cpu = self
+                .affinity_dialog_state
+                .selected_cpus
+                .len()
+                .saturating_sub(1);
+        } else if self.oom_dialog_state.is_showing_oom {
+            self.oom_dialog_state.oom_score_adj = process_oom::MAX_OOM_SCORE_ADJ;
+        } else if self.files_dialog_state.is_showing_files {
+            self.files_dialog_state.current_index = self
+                .files_dialog_state
+                .get_filtered_files()
+                .len()
+                .saturating_sub(1);
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Kill(MAX_SIGNAL);
         }
@@ -2371,6 +3105,9 @@ impl App {
                 }
                 BottomWidgetType::ProcSort => self.change_process_sort_position(amount),
                 BottomWidgetType::Temp => self.change_temp_position(amount),
+                BottomWidgetType::NetInterfaces => self.change_net_interfaces_position(amount),
+                BottomWidgetType::Protocols => self.change_protocols_position(amount),
+                BottomWidgetType::Sockets => self.change_sockets_position(amount),
                 BottomWidgetType::Disk => self.increment_disk_position(amount),
                 BottomWidgetType::CpuLegend => self.change_cpu_legend_position(amount),
                 _ => {}
@@ -2494,6 +3231,97 @@ impl App {
         }
     }
 
+    fn change_net_interfaces_position(&mut self, num_to_change_by: i64) {
+        if let Some(net_interfaces_widget_state) = self
+            .net_interfaces_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            let current_posn = net_interfaces_widget_state
+                .scroll_state
+                .current_scroll_position;
+
+            if current_posn as i64 + num_to_change_by < 0 {
+                net_interfaces_widget_state
+                    .scroll_state
+                    .current_scroll_position = 0;
+            } else if current_posn as i64 + num_to_change_by
+                >= self.canvas_data.interface_data.len() as i64
+            {
+                net_interfaces_widget_state
+                    .scroll_state
+                    .current_scroll_position =
+                    self.canvas_data.interface_data.len().saturating_sub(1);
+            } else {
+                net_interfaces_widget_state
+                    .scroll_state
+                    .current_scroll_position = (current_posn as i64 + num_to_change_by) as usize;
+            }
+
+            if num_to_change_by < 0 {
+                net_interfaces_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+            } else {
+                net_interfaces_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
+            }
+        }
+    }
+
+    fn change_sockets_position(&mut self, num_to_change_by: i64) {
+        if let Some(sockets_widget_state) = self
+            .sockets_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            let current_posn = sockets_widget_state.scroll_state.current_scroll_position;
+
+            if current_posn as i64 + num_to_change_by < 0 {
+                sockets_widget_state.scroll_state.current_scroll_position = 0;
+            } else if current_posn as i64 + num_to_change_by
+                >= self.canvas_data.socket_data.len() as i64
+            {
+                sockets_widget_state.scroll_state.current_scroll_position =
+                    self.canvas_data.socket_data.len().saturating_sub(1);
+            } else {
+                sockets_widget_state.scroll_state.current_scroll_position =
+                    (current_posn as i64 + num_to_change_by) as usize;
+            }
+
+            if num_to_change_by < 0 {
+                sockets_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+            } else {
+                sockets_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
+            }
+        }
+    }
+
+    fn change_protocols_position(&mut self, num_to_change_by: i64) {
+        if let Some(protocols_widget_state) = self
+            .protocols_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            let current_posn = protocols_widget_state.scroll_state.current_scroll_position;
+
+            if current_posn as i64 + num_to_change_by < 0 {
+                protocols_widget_state.scroll_state.current_scroll_position = 0;
+            } else if current_posn as i64 + num_to_change_by
+                >= self.canvas_data.protocol_data.len() as i64
+            {
+                protocols_widget_state.scroll_state.current_scroll_position =
+                    self.canvas_data.protocol_data.len().saturating_sub(1);
+            } else {
+                protocols_widget_state.scroll_state.current_scroll_position =
+                    (current_posn as i64 + num_to_change_by) as usize;
+            }
+
+            if num_to_change_by < 0 {
+                protocols_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+            } else {
+                protocols_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
+            }
+        }
+    }
+
     fn increment_disk_position(&mut self, num_to_change_by: i64) {
         if let Some(disk_widget_state) = self
             .disk_state
@@ -2501,9 +3329,14 @@ impl App {
             .get_mut(&self.current_widget.widget_id)
         {
             let current_posn = disk_widget_state.scroll_state.current_scroll_position;
+            let num_disks = self
+                .canvas_data
+                .disk_data
+                .get(&self.current_widget.widget_id)
+                .map_or(0, |disk_data| disk_data.len());
 
             if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by < self.canvas_data.disk_data.len() as i64
+                && current_posn as i64 + num_to_change_by < num_disks as i64
             {
                 disk_widget_state.scroll_state.current_scroll_position =
                     (current_posn as i64 + num_to_change_by) as usize;
@@ -2541,6 +3374,13 @@ impl App {
     }
 
     pub fn handle_scroll_up(&mut self) {
+        if self.affinity_dialog_state.is_showing_affinity
+            || self.oom_dialog_state.is_showing_oom
+            || self.files_dialog_state.is_showing_files
+        {
+            self.on_up_key();
+            return;
+        }
         if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_family = "unix")]
             {
@@ -2558,6 +3398,13 @@ impl App {
     }
 
     pub fn handle_scroll_down(&mut self) {
+        if self.affinity_dialog_state.is_showing_affinity
+            || self.oom_dialog_state.is_showing_oom
+            || self.files_dialog_state.is_showing_files
+        {
+            self.on_down_key();
+            return;
+        }
         if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_family = "unix")]
             {
@@ -2698,6 +3545,82 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Battery => {
+                if let Some(battery_widget_state) = self
+                    .battery_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = battery_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        battery_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if battery_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        battery_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::LoadAvg => {
+                if let Some(load_avg_widget_state) = self
+                    .load_avg_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = load_avg_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        load_avg_widget_state.current_display_time = new_time;
+                        self.load_avg_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            load_avg_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if load_avg_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        load_avg_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.load_avg_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            load_avg_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Protocols => {
+                if let Some(protocols_widget_state) = self
+                    .protocols_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = protocols_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        protocols_widget_state.current_display_time = new_time;
+                        self.protocols_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            protocols_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if protocols_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        protocols_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.protocols_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            protocols_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2779,6 +3702,82 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::Battery => {
+                if let Some(battery_widget_state) = self
+                    .battery_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = battery_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        battery_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if battery_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        battery_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::LoadAvg => {
+                if let Some(load_avg_widget_state) = self
+                    .load_avg_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = load_avg_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        load_avg_widget_state.current_display_time = new_time;
+                        self.load_avg_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            load_avg_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if load_avg_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        load_avg_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.load_avg_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            load_avg_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Protocols => {
+                if let Some(protocols_widget_state) = self
+                    .protocols_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = protocols_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        protocols_widget_state.current_display_time = new_time;
+                        self.protocols_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            protocols_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if protocols_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        protocols_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.protocols_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            protocols_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2825,15 +3824,130 @@ impl App {
         }
     }
 
+    fn reset_load_avg_zoom(&mut self) {
+        if let Some(load_avg_widget_state) = self
+            .load_avg_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            load_avg_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.load_avg_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                load_avg_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_protocols_zoom(&mut self) {
+        if let Some(protocols_widget_state) = self
+            .protocols_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            protocols_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            self.protocols_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                protocols_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_battery_zoom(&mut self) {
+        if let Some(battery_widget_state) = self
+            .battery_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            battery_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                battery_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
+            BottomWidgetType::Battery => self.reset_battery_zoom(),
+            BottomWidgetType::LoadAvg => self.reset_load_avg_zoom(),
+            BottomWidgetType::Protocols => self.reset_protocols_zoom(),
             _ => {}
         }
     }
 
+    /// Right-clicking on a process selects it, and then suspends it, or resumes it if it is
+    /// already stopped.
+    #[cfg(target_family = "unix")]
+    pub fn on_right_mouse_up(&mut self, x: u16, y: u16) {
+        if self.is_in_dialog() || self.is_config_open {
+            return;
+        }
+
+        let clicked_widget = self
+            .widget_map
+            .values()
+            .find(|widget| {
+                if let (BottomWidgetType::Proc, Some((tlc_x, tlc_y)), Some((brc_x, brc_y))) = (
+                    &widget.widget_type,
+                    widget.top_left_corner,
+                    widget.bottom_right_corner,
+                ) {
+                    (x >= tlc_x && y >= tlc_y) && (x < brc_x && y < brc_y)
+                } else {
+                    false
+                }
+            })
+            .cloned();
+
+        if let Some(clicked_widget) = clicked_widget {
+            if let (Some((_tlc_x, tlc_y)), Some((_brc_x, brc_y))) = (
+                clicked_widget.top_left_corner,
+                clicked_widget.bottom_right_corner,
+            ) {
+                // Same offsets as a left click, so that the header, gap, and borders are skipped.
+                let border_offset = if self.is_drawing_border() { 1 } else { 0 };
+                let header_gap_offset = 1 + if self.is_drawing_gap(&clicked_widget) {
+                    self.app_config_fields.table_gap
+                } else {
+                    0
+                };
+                let offset = border_offset + header_gap_offset;
+                let clicked_entry = y - tlc_y;
+                if clicked_entry < offset || y >= brc_y - border_offset {
+                    return;
+                }
+
+                let widget_id = clicked_widget.widget_id;
+                self.current_widget = clicked_widget;
+
+                let process_count = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id)
+                    .map_or(0, |processes| processes.len());
+                if let Some(proc_widget_state) = self.proc_state.get_widget_state(widget_id) {
+                    if let Some(visual_index) =
+                        proc_widget_state.scroll_state.table_state.selected()
+                    {
+                        let num_to_change_by =
+                            (clicked_entry - offset) as i64 - visual_index as i64;
+                        let clicked_position =
+                            proc_widget_state.scroll_state.current_scroll_position as i64
+                                + num_to_change_by;
+
+                        // Clicking past the last process shouldn't signal anything.
+                        if clicked_position >= 0 && (clicked_position as usize) < process_count {
+                            self.change_process_position(num_to_change_by);
+                            self.toggle_suspend_highlighted_process();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Moves the mouse to the widget that was clicked on, then propagates the click down to be
     /// handled by the widget specifically.
     pub fn on_left_mouse_up(&mut self, x: u16, y: u16) {
@@ -2910,6 +4024,20 @@ impl App {
         // Second short circuit --- are we in the dd dialog state?  If so, only check yes/no/signals
         // and bail after.
         if self.is_in_dialog() {
+            if self.affinity_dialog_state.is_showing_affinity {
+                if let Some((_, _, _, _, cpu)) =
+                    self.affinity_dialog_state.button_positions.iter().find(
+                        |(tl_x, tl_y, br_x, br_y, _cpu)| {
+                            (x >= *tl_x && y >= *tl_y) && (x <= *br_x && y <= *br_y)
+                        },
+                    )
+                {
+                    self.affinity_dialog_state.current_cpu = *cpu;
+                    self.affinity_dialog_state.toggle_current_cpu();
+                }
+                return;
+            }
+
             match self.delete_dialog_state.button_positions.iter().find(
                 |(tl_x, tl_y, br_x, br_y, _idx)| {
                     (x >= *tl_x && y >= *tl_y) && (x <= *br_x && y <= *br_y)
@@ -2982,6 +4110,9 @@ impl App {
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
                     | BottomWidgetType::Temp
+                    | BottomWidgetType::NetInterfaces
+                    | BottomWidgetType::Protocols
+                    | BottomWidgetType::Sockets
                     | BottomWidgetType::Disk => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
@@ -3069,6 +4200,52 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::NetInterfaces => {
+                                    if let Some(net_interfaces_widget_state) = self
+                                        .net_interfaces_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) = net_interfaces_widget_state
+                                            .scroll_state
+                                            .table_state
+                                            .selected()
+                                        {
+                                            self.change_net_interfaces_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::Sockets => {
+                                    if let Some(sockets_widget_state) = self
+                                        .sockets_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            sockets_widget_state.scroll_state.table_state.selected()
+                                        {
+                                            self.change_sockets_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::Protocols => {
+                                    if let Some(protocols_widget_state) = self
+                                        .protocols_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) = protocols_widget_state
+                                            .scroll_state
+                                            .table_state
+                                            .selected()
+                                        {
+                                            self.change_protocols_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 BottomWidgetType::Disk => {
                                     if let Some(disk_widget_state) = self
                                         .disk_state
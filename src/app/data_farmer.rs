@@ -14,17 +14,31 @@
 /// more points as this is used!
 use once_cell::sync::Lazy;
 
-use std::{time::Instant, vec::Vec};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+    vec::Vec,
+};
 
 #[cfg(feature = "battery")]
 use crate::data_harvester::batteries;
 
 use crate::{
-    data_harvester::{cpu, disks, memory, network, processes, temperature, Data},
+    data_harvester::{
+        cpu, disks, memory, network, processes, protocols, system, temperature, Data,
+    },
     utils::gen_util::{get_decimal_bytes, GIGA_LIMIT},
+    Pid,
 };
+use fxhash::FxHashMap;
 use regex::Regex;
 
+/// How far back to look when calculating how quickly a disk is filling up.
+const DISK_FILL_RATE_WINDOW: Duration = Duration::from_secs(120);
+
+/// How far back to keep per-process read and write rates.
+pub const PROCESS_IO_HISTORY_WINDOW: Duration = Duration::from_secs(60);
+
 pub type TimeOffset = f64;
 pub type Value = f64;
 
@@ -32,10 +46,21 @@ pub type Value = f64;
 pub struct TimedData {
     pub rx_data: Value,
     pub tx_data: Value,
+    pub rx_packets_data: Value,
+    pub tx_packets_data: Value,
+    pub rx_errors_data: Value,
+    pub tx_errors_data: Value,
+    pub rx_drops_data: Value,
+    pub tx_drops_data: Value,
+    pub wireless_data: HashMap<String, Value>, // Link quality, keyed by interface name.
+    pub tcp_retransmit_data: Value,            // TCP segments retransmitted per second.
+    pub socket_data: Vec<Option<Value>>,       // In the same order as the socket harvest.
     pub cpu_data: Vec<Value>,
     pub load_avg_data: [f32; 3],
     pub mem_data: Option<Value>,
     pub swap_data: Option<Value>,
+    #[cfg(feature = "battery")]
+    pub battery_data: Vec<(Value, Value)>, // Charge percentage and power consumption in watts.
 }
 
 /// AppCollection represents the pooled data stored within the main app
@@ -53,16 +78,28 @@ pub struct DataCollection {
     pub frozen_instant: Option<Instant>,
     pub timed_data_vec: Vec<(Instant, TimedData)>,
     pub network_harvest: network::NetworkHarvest,
+    pub interface_harvest: Vec<network::InterfaceHarvest>,
+    pub wireless_harvest: Vec<network::WirelessHarvest>,
+    pub protocol_harvest: protocols::ProtocolHarvest,
+    /// The per-second rates of each protocol statistic, in the same order as the protocol harvest.
+    pub protocol_rates: Vec<f64>,
+    pub socket_harvest: protocols::SocketHarvest,
     pub memory_harvest: memory::MemHarvest,
     pub swap_harvest: memory::MemHarvest,
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
     pub process_harvest: Vec<processes::ProcessHarvest>,
+    /// A short history of read and write bytes per second for each process, keyed by PID.
+    pub process_io_history: FxHashMap<Pid, VecDeque<(Instant, u64, u64)>>,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IoHarvest,
     pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
+    pub block_device_harvest: disks::BlockDeviceMap,
+    /// A short history of used space per mount point, used to calculate fill rates.
+    pub disk_usage_history: HashMap<String, VecDeque<(Instant, u64)>>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    pub system_harvest: system::SystemHarvest,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryHarvest>,
 }
@@ -74,16 +111,25 @@ impl Default for DataCollection {
             frozen_instant: None,
             timed_data_vec: Vec::default(),
             network_harvest: network::NetworkHarvest::default(),
+            interface_harvest: Vec::default(),
+            wireless_harvest: Vec::default(),
+            protocol_harvest: protocols::ProtocolHarvest::default(),
+            protocol_rates: Vec::default(),
+            socket_harvest: protocols::SocketHarvest::default(),
             memory_harvest: memory::MemHarvest::default(),
             swap_harvest: memory::MemHarvest::default(),
             cpu_harvest: cpu::CpuHarvest::default(),
             load_avg_harvest: cpu::LoadAvgHarvest::default(),
             process_harvest: Vec::default(),
+            process_io_history: FxHashMap::default(),
             disk_harvest: Vec::default(),
             io_harvest: disks::IoHarvest::default(),
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
+            block_device_harvest: disks::BlockDeviceMap::default(),
+            disk_usage_history: HashMap::default(),
             temp_harvest: Vec::default(),
+            system_harvest: system::SystemHarvest::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
         }
@@ -94,14 +140,23 @@ impl DataCollection {
     pub fn reset(&mut self) {
         self.timed_data_vec = Vec::default();
         self.network_harvest = network::NetworkHarvest::default();
+        self.interface_harvest = Vec::default();
+        self.wireless_harvest = Vec::default();
+        self.protocol_harvest = protocols::ProtocolHarvest::default();
+        self.protocol_rates = Vec::default();
+        self.socket_harvest = protocols::SocketHarvest::default();
         self.memory_harvest = memory::MemHarvest::default();
         self.swap_harvest = memory::MemHarvest::default();
         self.cpu_harvest = cpu::CpuHarvest::default();
         self.process_harvest = Vec::default();
+        self.process_io_history = FxHashMap::default();
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IoHarvest::default();
         self.io_labels_and_prev = Vec::default();
+        self.block_device_harvest = disks::BlockDeviceMap::default();
+        self.disk_usage_history = HashMap::default();
         self.temp_harvest = Vec::default();
+        self.system_harvest = system::SystemHarvest::default();
         #[cfg(feature = "battery")]
         {
             self.battery_harvest = Vec::default();
@@ -142,6 +197,26 @@ impl DataCollection {
             self.eat_network(network, &mut new_entry);
         }
 
+        // Network interfaces
+        if let Some(interfaces) = harvested_data.interfaces {
+            self.interface_harvest = interfaces;
+        }
+
+        // Wireless
+        if let Some(wireless) = harvested_data.wireless {
+            self.eat_wireless(wireless, &mut new_entry);
+        }
+
+        // Protocols
+        if let Some(protocols) = harvested_data.protocols {
+            self.eat_protocols(protocols, &mut new_entry, harvested_time);
+        }
+
+        // Sockets
+        if let Some(sockets) = harvested_data.sockets {
+            self.eat_sockets(sockets, &mut new_entry);
+        }
+
         // Memory and Swap
         if let (Some(memory), Some(swap)) = (harvested_data.memory, harvested_data.swap) {
             self.eat_memory_and_swap(memory, swap, &mut new_entry);
@@ -169,16 +244,26 @@ impl DataCollection {
             }
         }
 
+        // Block devices
+        if let Some(block_devices) = harvested_data.block_devices {
+            self.block_device_harvest = block_devices;
+        }
+
+        // System
+        if let Some(system) = harvested_data.system {
+            self.system_harvest = system;
+        }
+
         // Processes
         if let Some(list_of_processes) = harvested_data.list_of_processes {
-            self.eat_proc(list_of_processes);
+            self.eat_proc(list_of_processes, harvested_time);
         }
 
         #[cfg(feature = "battery")]
         {
             // Battery
             if let Some(list_of_batteries) = harvested_data.list_of_batteries {
-                self.eat_battery(list_of_batteries);
+                self.eat_battery(list_of_batteries, &mut new_entry);
             }
         }
 
@@ -212,10 +297,73 @@ impl DataCollection {
             new_entry.tx_data = network.tx as f64;
         }
 
+        // Packets, errors, and drops
+        let packets = &network.packets;
+        new_entry.rx_packets_data = packets.rx_packets;
+        new_entry.tx_packets_data = packets.tx_packets;
+        new_entry.rx_errors_data = packets.rx_errors;
+        new_entry.tx_errors_data = packets.tx_errors;
+        new_entry.rx_drops_data = packets.rx_drops;
+        new_entry.tx_drops_data = packets.tx_drops;
+
         // In addition copy over latest data for easy reference
         self.network_harvest = network;
     }
 
+    fn eat_wireless(&mut self, wireless: Vec<network::WirelessHarvest>, new_entry: &mut TimedData) {
+        new_entry.wireless_data = wireless
+            .iter()
+            .map(|wireless| (wireless.name.clone(), wireless.link_quality.unwrap_or(0.0)))
+            .collect();
+
+        self.wireless_harvest = wireless;
+    }
+
+    fn eat_sockets(&mut self, sockets: protocols::SocketHarvest, new_entry: &mut TimedData) {
+        new_entry.socket_data = sockets
+            .iter()
+            .map(|stat| stat.value.map(|value| value as f64))
+            .collect();
+
+        self.socket_harvest = sockets;
+    }
+
+    fn eat_protocols(
+        &mut self, protocols: protocols::ProtocolHarvest, new_entry: &mut TimedData,
+        harvested_time: Instant,
+    ) {
+        let time_since_last_harvest = harvested_time
+            .duration_since(self.current_instant)
+            .as_secs_f64();
+
+        self.protocol_rates = protocols
+            .iter()
+            .map(|stat| {
+                match self
+                    .protocol_harvest
+                    .iter()
+                    .find(|prev_stat| prev_stat.name == stat.name)
+                {
+                    Some(prev_stat) if time_since_last_harvest > 0.0 => {
+                        stat.total.saturating_sub(prev_stat.total) as f64 / time_since_last_harvest
+                    }
+                    _ => 0.0,
+                }
+            })
+            .collect();
+
+        if let Some(retransmit_rate) = protocols
+            .iter()
+            .zip(&self.protocol_rates)
+            .find(|(stat, _rate)| stat.name == protocols::TCP_RETRANSMITS)
+            .map(|(_stat, rate)| *rate)
+        {
+            new_entry.tcp_retransmit_data = retransmit_rate;
+        }
+
+        self.protocol_harvest = protocols;
+    }
+
     fn eat_cpu(&mut self, cpu: Vec<cpu::CpuData>, new_entry: &mut TimedData) {
         // Note this only pre-calculates the data points - the names will be
         // within the local copy of cpu_harvest.  Since it's all sequential
@@ -315,16 +463,162 @@ impl DataCollection {
             }
         }
 
+        // Track used space over a short window, and drop any mounts that have gone away.
+        self.disk_usage_history
+            .retain(|mount_point, _| disks.iter().any(|disk| disk.mount_point == *mount_point));
+        for disk in &disks {
+            if let Some(used_space) = disk.used_space {
+                let history = self
+                    .disk_usage_history
+                    .entry(disk.mount_point.clone())
+                    .or_default();
+                history.push_back((harvested_time, used_space));
+
+                while let Some((instant, _)) = history.front() {
+                    if harvested_time.duration_since(*instant) > DISK_FILL_RATE_WINDOW {
+                        history.pop_front();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
         self.disk_harvest = disks;
         self.io_harvest = io;
     }
 
-    fn eat_proc(&mut self, list_of_processes: Vec<processes::ProcessHarvest>) {
+    /// Returns how many seconds are left until a disk is full, based on how quickly its used space
+    /// has grown recently.  Returns [`None`] if it isn't growing.
+    pub fn get_secs_until_disk_full(&self, disk: &disks::DiskHarvest) -> Option<u64> {
+        let history = self.disk_usage_history.get(&disk.mount_point)?;
+        let (first_instant, first_used) = history.front()?;
+        let (last_instant, last_used) = history.back()?;
+
+        let elapsed = last_instant.duration_since(*first_instant).as_secs_f64();
+        if elapsed <= 0.0 || last_used <= first_used {
+            return None;
+        }
+
+        let bytes_per_sec = (last_used - first_used) as f64 / elapsed;
+        Some((disk.free_space? as f64 / bytes_per_sec).round() as u64)
+    }
+
+    fn eat_proc(
+        &mut self, list_of_processes: Vec<processes::ProcessHarvest>, harvested_time: Instant,
+    ) {
+        // Track I/O rates over a short window, and drop any processes that have gone away.
+        let mut process_io_history = FxHashMap::default();
+        for process in &list_of_processes {
+            let mut history = self
+                .process_io_history
+                .remove(&process.pid)
+                .unwrap_or_default();
+            history.push_back((
+                harvested_time,
+                process.read_bytes_per_sec,
+                process.write_bytes_per_sec,
+            ));
+
+            while let Some((instant, _, _)) = history.front() {
+                if harvested_time.duration_since(*instant) > PROCESS_IO_HISTORY_WINDOW {
+                    history.pop_front();
+                } else {
+                    break;
+                }
+            }
+            process_io_history.insert(process.pid, history);
+        }
+
+        self.process_io_history = process_io_history;
         self.process_harvest = list_of_processes;
     }
 
     #[cfg(feature = "battery")]
-    fn eat_battery(&mut self, list_of_batteries: Vec<batteries::BatteryHarvest>) {
+    fn eat_battery(
+        &mut self, list_of_batteries: Vec<batteries::BatteryHarvest>, new_entry: &mut TimedData,
+    ) {
+        new_entry.battery_data = list_of_batteries
+            .iter()
+            .map(|battery| (battery.charge_percent, battery.power_consumption_rate_watts))
+            .collect();
         self.battery_harvest = list_of_batteries;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_process(pid: Pid, read_bytes_per_sec: u64) -> processes::ProcessHarvest {
+        processes::ProcessHarvest {
+            pid,
+            read_bytes_per_sec,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_process_io_history_prunes_old_entries() {
+        let mut data = DataCollection::default();
+        let start = Instant::now();
+
+        data.eat_proc(vec![get_process(1, 10)], start);
+        data.eat_proc(vec![get_process(1, 20)], start + Duration::from_secs(30));
+        assert_eq!(data.process_io_history[&1].len(), 2);
+
+        // The first entry is now outside the window, but the second is right on its edge.
+        data.eat_proc(
+            vec![get_process(1, 30)],
+            start + Duration::from_secs(30) + PROCESS_IO_HISTORY_WINDOW,
+        );
+        let history = &data.process_io_history[&1];
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history.iter().map(|(_, read, _)| *read).collect::<Vec<_>>(),
+            vec![20, 30]
+        );
+    }
+
+    #[test]
+    fn test_process_io_history_drops_exited_processes() {
+        let mut data = DataCollection::default();
+        let start = Instant::now();
+
+        data.eat_proc(vec![get_process(1, 10), get_process(2, 10)], start);
+        data.eat_proc(vec![get_process(2, 20)], start + Duration::from_secs(1));
+
+        assert!(!data.process_io_history.contains_key(&1));
+        assert_eq!(data.process_io_history[&2].len(), 2);
+
+        // A reused PID starts with a fresh history.
+        data.eat_proc(vec![get_process(1, 30)], start + Duration::from_secs(2));
+        assert_eq!(data.process_io_history[&1].len(), 1);
+        assert!(!data.process_io_history.contains_key(&2));
+    }
+
+    #[test]
+    fn test_eat_network_keeps_error_and_drop_rates() {
+        let mut data = DataCollection::default();
+        let mut new_entry = TimedData::default();
+        let network = network::NetworkHarvest {
+            packets: network::PacketRates {
+                rx_packets: 10.0,
+                tx_packets: 20.0,
+                rx_errors: 0.5,
+                tx_errors: 1.5,
+                rx_drops: 2.0,
+                tx_drops: 0.25,
+            },
+            ..Default::default()
+        };
+
+        data.eat_network(network, &mut new_entry);
+        assert_eq!(new_entry.rx_packets_data, 10.0);
+        assert_eq!(new_entry.tx_packets_data, 20.0);
+        assert_eq!(new_entry.rx_errors_data, 0.5);
+        assert_eq!(new_entry.tx_errors_data, 1.5);
+        assert_eq!(new_entry.rx_drops_data, 2.0);
+        assert_eq!(new_entry.tx_drops_data, 0.25);
+    }
+}
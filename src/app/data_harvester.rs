@@ -1,6 +1,6 @@
 //! This is the main file to house data collection functions.
 
-use std::time::Instant;
+use std::{collections::HashMap, time::Instant};
 
 #[cfg(target_os = "linux")]
 use fxhash::FxHashMap;
@@ -24,6 +24,8 @@ pub mod disks;
 pub mod memory;
 pub mod network;
 pub mod processes;
+pub mod protocols;
+pub mod system;
 pub mod temperature;
 
 #[derive(Clone, Debug)]
@@ -35,9 +37,15 @@ pub struct Data {
     pub swap: Option<memory::MemHarvest>,
     pub temperature_sensors: Option<Vec<temperature::TempHarvest>>,
     pub network: Option<network::NetworkHarvest>,
+    pub interfaces: Option<Vec<network::InterfaceHarvest>>,
+    pub wireless: Option<Vec<network::WirelessHarvest>>,
+    pub protocols: Option<protocols::ProtocolHarvest>,
+    pub sockets: Option<protocols::SocketHarvest>,
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IoHarvest>,
+    pub block_devices: Option<disks::BlockDeviceMap>,
+    pub system: Option<system::SystemHarvest>,
     #[cfg(feature = "battery")]
     pub list_of_batteries: Option<Vec<batteries::BatteryHarvest>>,
 }
@@ -54,7 +62,13 @@ impl Default for Data {
             list_of_processes: None,
             disks: None,
             io: None,
+            block_devices: None,
+            system: None,
             network: None,
+            interfaces: None,
+            wireless: None,
+            protocols: None,
+            sockets: None,
             #[cfg(feature = "battery")]
             list_of_batteries: None,
         }
@@ -97,7 +111,10 @@ pub struct DataCollector {
     last_collection_time: Instant,
     total_rx: u64,
     total_tx: u64,
+    total_packets: network::PacketCounts,
+    prev_interface_totals: HashMap<String, (u64, u64)>,
     show_average_cpu: bool,
+    get_all_partitions: bool,
     widgets_to_harvest: UsedWidgets,
     #[cfg(feature = "battery")]
     battery_manager: Option<Manager>,
@@ -126,7 +143,10 @@ impl DataCollector {
             last_collection_time: Instant::now(),
             total_rx: 0,
             total_tx: 0,
+            total_packets: network::PacketCounts::default(),
+            prev_interface_totals: HashMap::new(),
             show_average_cpu: false,
+            get_all_partitions: false,
             widgets_to_harvest: UsedWidgets::default(),
             #[cfg(feature = "battery")]
             battery_manager: None,
@@ -153,7 +173,9 @@ impl DataCollector {
             }
 
             // Refresh network list once...
-            if cfg!(target_os = "windows") && self.widgets_to_harvest.use_net {
+            if cfg!(target_os = "windows")
+                && (self.widgets_to_harvest.use_net || self.widgets_to_harvest.use_net_interfaces)
+            {
                 self.sys.refresh_networks_list();
             }
         }
@@ -207,26 +229,35 @@ impl DataCollector {
         self.show_average_cpu = show_average_cpu;
     }
 
+    pub fn set_get_all_partitions(&mut self, get_all_partitions: bool) {
+        self.get_all_partitions = get_all_partitions;
+    }
+
     pub async fn update_data(&mut self) {
         #[cfg(not(target_os = "linux"))]
         {
-            if self.widgets_to_harvest.use_proc {
+            if self.widgets_to_harvest.use_proc || self.widgets_to_harvest.use_summary {
                 self.sys.refresh_cpu();
                 self.sys.refresh_processes();
             }
+            if self.widgets_to_harvest.use_summary {
+                self.sys.refresh_users_list();
+            }
             if self.widgets_to_harvest.use_temp {
                 self.sys.refresh_components();
             }
 
-            if cfg!(target_os = "windows") && self.widgets_to_harvest.use_net {
+            if cfg!(target_os = "windows")
+                && (self.widgets_to_harvest.use_net || self.widgets_to_harvest.use_net_interfaces)
+            {
                 self.sys.refresh_networks();
             }
         }
 
         let current_instant = std::time::Instant::now();
 
-        // CPU
-        if self.widgets_to_harvest.use_cpu {
+        // CPU, which is also needed for the core count when showing load averages
+        if self.widgets_to_harvest.use_cpu || self.widgets_to_harvest.use_load_avg {
             if let Ok(cpu_data) = cpu::get_cpu_data_list(
                 self.show_average_cpu,
                 &mut self.previous_cpu_times,
@@ -236,10 +267,15 @@ impl DataCollector {
             {
                 self.data.cpu = Some(cpu_data);
             }
+        }
 
-            #[cfg(target_family = "unix")]
+        #[cfg(target_family = "unix")]
+        {
+            // Load Average
+            if self.widgets_to_harvest.use_cpu
+                || self.widgets_to_harvest.use_load_avg
+                || self.widgets_to_harvest.use_summary
             {
-                // Load Average
                 if let Ok(load_avg_data) = cpu::get_load_avg().await {
                     self.data.load_avg = Some(load_avg_data);
                 }
@@ -293,6 +329,7 @@ impl DataCollector {
                     self.last_collection_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
+                    &mut self.total_packets,
                     current_instant,
                     self.widgets_to_harvest.use_net,
                     &self.filters.net_filter,
@@ -304,17 +341,43 @@ impl DataCollector {
                     self.last_collection_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
+                    &mut self.total_packets,
                     current_instant,
                     self.widgets_to_harvest.use_net,
                     &self.filters.net_filter,
                 )
             }
         };
+        let interface_data_fut = {
+            #[cfg(target_os = "windows")]
+            {
+                network::get_interface_data(
+                    &self.sys,
+                    self.last_collection_time,
+                    &mut self.prev_interface_totals,
+                    current_instant,
+                    self.widgets_to_harvest.use_net_interfaces,
+                    &self.filters.net_filter,
+                )
+            }
+            #[cfg(not(target_os = "windows"))]
+            {
+                network::get_interface_data(
+                    self.last_collection_time,
+                    &mut self.prev_interface_totals,
+                    current_instant,
+                    self.widgets_to_harvest.use_net_interfaces,
+                    &self.filters.net_filter,
+                )
+            }
+        };
         let mem_data_fut = memory::get_mem_data(self.widgets_to_harvest.use_mem);
         let disk_data_fut = disks::get_disk_usage(
             self.widgets_to_harvest.use_disk,
+            self.get_all_partitions,
             &self.filters.disk_filter,
             &self.filters.mount_filter,
+            &self.filters.fs_filter,
         );
         let disk_io_usage_fut = disks::get_io_usage(self.widgets_to_harvest.use_disk);
         let temp_data_fut = {
@@ -338,8 +401,9 @@ impl DataCollector {
             }
         };
 
-        let (net_data, mem_res, disk_res, io_res, temp_res) = join!(
+        let (net_data, interface_res, mem_res, disk_res, io_res, temp_res) = join!(
             network_data_fut,
+            interface_data_fut,
             mem_data_fut,
             disk_data_fut,
             disk_io_usage_fut,
@@ -350,10 +414,26 @@ impl DataCollector {
             if let Some(net_data) = &net_data {
                 self.total_rx = net_data.total_rx;
                 self.total_tx = net_data.total_tx;
+                self.total_packets = net_data.total_packets;
             }
             self.data.network = net_data;
         }
 
+        if let Ok(interfaces) = interface_res {
+            self.data.interfaces = interfaces;
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.data.wireless = network::wireless::get_wireless_data(
+                self.widgets_to_harvest.use_net || self.widgets_to_harvest.use_net_interfaces,
+                &self.filters.net_filter,
+            );
+            self.data.protocols =
+                protocols::get_protocol_data(self.widgets_to_harvest.use_protocols);
+            self.data.sockets = protocols::get_socket_data(self.widgets_to_harvest.use_sockets);
+        }
+
         if let Ok(memory) = mem_res.0 {
             self.data.memory = memory;
         }
@@ -370,6 +450,22 @@ impl DataCollector {
             self.data.io = io;
         }
 
+        self.data.block_devices = disks::get_block_devices(self.widgets_to_harvest.use_disk);
+
+        self.data.system = {
+            #[cfg(target_os = "linux")]
+            {
+                system::get_system_data(
+                    self.widgets_to_harvest.use_summary,
+                    self.data.list_of_processes.as_deref(),
+                )
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                system::get_system_data(&self.sys, self.widgets_to_harvest.use_summary)
+            }
+        };
+
         if let Ok(temp) = temp_res {
             self.data.temperature_sensors = temp;
         }
@@ -10,7 +10,9 @@
 //! For more information, see https://github.com/svartalf/rust-battery
 
 use battery::{
-    units::{power::watt, ratio::percent, time::second},
+    units::{
+        electric_potential::volt, energy::watt_hour, power::watt, ratio::percent, time::second,
+    },
     Battery, Manager,
 };
 
@@ -21,6 +23,14 @@ pub struct BatteryHarvest {
     pub secs_until_empty: Option<i64>,
     pub power_consumption_rate_watts: f64,
     pub health_percent: f64,
+    pub state: String,
+    pub cycle_count: Option<u32>,
+    pub energy_full_wh: f64,
+    pub energy_full_design_wh: f64,
+    pub voltage_volts: f64,
+    pub technology: String,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
 }
 
 pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<BatteryHarvest> {
@@ -40,6 +50,16 @@ pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<Ba
                     charge_percent: f64::from(battery.state_of_charge().get::<percent>()),
                     power_consumption_rate_watts: f64::from(battery.energy_rate().get::<watt>()),
                     health_percent: f64::from(battery.state_of_health().get::<percent>()),
+                    state: battery.state().to_string(),
+                    cycle_count: battery.cycle_count(),
+                    energy_full_wh: f64::from(battery.energy_full().get::<watt_hour>()),
+                    energy_full_design_wh: f64::from(
+                        battery.energy_full_design().get::<watt_hour>(),
+                    ),
+                    voltage_volts: f64::from(battery.voltage().get::<volt>()),
+                    technology: battery.technology().to_string(),
+                    vendor: battery.vendor().map(|vendor| vendor.trim().to_string()),
+                    model: battery.model().map(|model| model.trim().to_string()),
                 })
             } else {
                 None
@@ -1,7 +1,18 @@
 //! Linux-specific things for Heim disk data collection.
 
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
 use heim::disk::Partition;
 
+use super::{BlockDeviceHarvest, BlockDeviceMap};
+
+const SYS_BLOCK_PATH: &str = "/sys/block";
+const MD_DEVICE_PATH: &str = "/dev/md";
+
 pub fn get_device_name(partition: &Partition) -> String {
     if let Some(device) = partition.device() {
         // See if this disk is actually mounted elsewhere on Linux...
@@ -32,3 +43,87 @@ pub fn get_device_name(partition: &Partition) -> String {
         "Name Unavailable".to_string()
     }
 }
+
+/// Returns the sorted names of all entries in a directory, or nothing if it can't be read.
+fn read_dir_names(path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = if let Ok(entries) = fs::read_dir(path) {
+        entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    names.sort();
+
+    names
+}
+
+/// Maps md kernel names (i.e. `md127`) to their named device in `/dev/md`, if there is one.
+fn get_md_names() -> HashMap<String, String> {
+    read_dir_names(Path::new(MD_DEVICE_PATH))
+        .into_iter()
+        .filter_map(|name| {
+            let device_path: PathBuf = [MD_DEVICE_PATH, &name].iter().collect();
+            let target = fs::read_link(&device_path).ok()?;
+            let kernel_name = target.file_name()?.to_str()?.to_string();
+
+            Some((kernel_name, device_path.to_string_lossy().to_string()))
+        })
+        .collect()
+}
+
+/// Returns the friendly name of a dm device as found in `/dev/mapper`, if this is one.
+fn get_dm_name(device_path: &Path) -> Option<String> {
+    let name = fs::read_to_string(device_path.join("dm").join("name")).ok()?;
+    let name = name.trim();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(format!("/dev/mapper/{}", name))
+    }
+}
+
+/// Builds the block device hierarchy from `/sys/block`, using partitions plus the `holders` and
+/// `slaves` of each device.
+pub fn get_block_devices(actually_get: bool) -> Option<BlockDeviceMap> {
+    if !actually_get {
+        return None;
+    }
+
+    let mut block_devices = BlockDeviceMap::default();
+    let md_names = get_md_names();
+
+    for device in read_dir_names(Path::new(SYS_BLOCK_PATH)) {
+        let device_path = Path::new(SYS_BLOCK_PATH).join(&device);
+        let mut children = Vec::new();
+
+        // Partitions show up as subdirectories of their disk with a "partition" file.
+        for entry in read_dir_names(&device_path) {
+            let partition_path = device_path.join(&entry);
+            if partition_path.join("partition").exists() {
+                block_devices.insert(
+                    entry.clone(),
+                    BlockDeviceHarvest {
+                        friendly_name: None,
+                        parents: vec![device.clone()],
+                        children: read_dir_names(&partition_path.join("holders")),
+                    },
+                );
+                children.push(entry);
+            }
+        }
+        children.extend(read_dir_names(&device_path.join("holders")));
+
+        block_devices.insert(
+            device.clone(),
+            BlockDeviceHarvest {
+                friendly_name: get_dm_name(&device_path).or_else(|| md_names.get(&device).cloned()),
+                parents: read_dir_names(&device_path.join("slaves")),
+                children,
+            },
+        );
+    }
+
+    Some(block_devices)
+}
@@ -1,3 +1,13 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+
 use crate::app::Filter;
 
 cfg_if::cfg_if! {
@@ -19,6 +29,20 @@ pub struct DiskHarvest {
     pub total_space: Option<u64>,
 }
 
+/// A block device and its position in the device hierarchy (disk → partition → dm/md device).
+#[derive(Debug, Clone, Default)]
+pub struct BlockDeviceHarvest {
+    /// A more readable name for the device, such as its `/dev/mapper` name.
+    pub friendly_name: Option<String>,
+    /// Kernel names of the devices this one is built on top of (its disk or slaves).
+    pub parents: Vec<String>,
+    /// Kernel names of the devices built on top of this one (its partitions or holders).
+    pub children: Vec<String>,
+}
+
+/// Block devices, keyed by their kernel name (i.e. `dm-3`).
+pub type BlockDeviceMap = HashMap<String, BlockDeviceHarvest>;
+
 #[derive(Clone, Debug)]
 pub struct IoData {
     pub read_bytes: u64,
@@ -57,22 +81,135 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
     Ok(Some(io_hash))
 }
 
+/// How long to wait for the usage of all non-physical partitions before giving up on them.
+const USAGE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Mount points that still have a usage call in flight.  Network mounts (NFS, CIFS, etc.) can block
+/// `statvfs` indefinitely if the remote end goes away, so these are skipped until the call returns
+/// rather than piling up more stuck threads.
+static PENDING_USAGE_CALLS: Lazy<Mutex<HashSet<PathBuf>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+type UsageReceiver = mpsc::Receiver<heim::Result<heim::disk::Usage>>;
+
+/// Starts getting the usage of a partition on a separate thread, returning `None` if a previous
+/// call for the same mount point has yet to return.
+fn spawn_usage_call(mount_point: &Path) -> Option<UsageReceiver> {
+    let mount_point = mount_point.to_path_buf();
+
+    if let Ok(mut pending) = PENDING_USAGE_CALLS.lock() {
+        if !pending.insert(mount_point.clone()) {
+            return None;
+        }
+    } else {
+        return None;
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let usage = futures::executor::block_on(heim::disk::usage(&mount_point));
+        if let Ok(mut pending) = PENDING_USAGE_CALLS.lock() {
+            pending.remove(&mount_point);
+        }
+        let _ = sender.send(usage);
+    });
+
+    Some(receiver)
+}
+
+/// Returns whether a partition should be shown, given the disk, mount, and filesystem filters.
+fn is_partition_kept(
+    name: &str, mount_point: &str, file_system: &str, disk_filter: &Option<Filter>,
+    mount_filter: &Option<Filter>, fs_filter: &Option<Filter>,
+) -> bool {
+    // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
+    //
+    // For implementation, we do this as follows:
+    // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
+    // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
+    // 3. Anything else is allowed.
+
+    let filter_check_map = [
+        (disk_filter, name),
+        (mount_filter, mount_point),
+        (fs_filter, file_system),
+    ];
+
+    // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
+    // immediately allow it!
+    let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
+        if let Some(filter) = filter {
+            if !filter.is_list_ignored {
+                for r in &filter.list {
+                    if r.is_match(text) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    });
+
+    if matches_allow_list {
+        true
+    } else {
+        // If it doesn't match an allow list, then check if it is denied.
+        // That is, if it matches in a reject filter, then reject.  Otherwise, we always keep it.
+        !filter_check_map.iter().any(|(filter, text)| {
+            if let Some(filter) = filter {
+                if filter.is_list_ignored {
+                    for r in &filter.list {
+                        if r.is_match(text) {
+                            return true;
+                        }
+                    }
+                }
+            }
+            false
+        })
+    }
+}
+
+/// Fills in the free, used, and total space of a disk, if its usage is known.
+fn with_usage(disk: DiskHarvest, usage: Option<heim::disk::Usage>) -> DiskHarvest {
+    match usage {
+        Some(usage) => DiskHarvest {
+            free_space: Some(usage.free().get::<heim::units::information::byte>()),
+            used_space: Some(usage.used().get::<heim::units::information::byte>()),
+            total_space: Some(usage.total().get::<heim::units::information::byte>()),
+            ..disk
+        },
+        None => disk,
+    }
+}
+
 pub async fn get_disk_usage(
-    actually_get: bool, disk_filter: &Option<Filter>, mount_filter: &Option<Filter>,
+    actually_get: bool, get_all_partitions: bool, disk_filter: &Option<Filter>,
+    mount_filter: &Option<Filter>, fs_filter: &Option<Filter>,
 ) -> crate::utils::error::Result<Option<Vec<DiskHarvest>>> {
     if !actually_get {
         return Ok(None);
     }
 
-    use futures::StreamExt;
+    use futures::{stream::BoxStream, StreamExt};
 
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
-    let partitions_stream = heim::disk::partitions_physical().await?;
+    let partitions_stream: BoxStream<'_, heim::Result<heim::disk::Partition>> =
+        if get_all_partitions {
+            heim::disk::partitions().await?.boxed()
+        } else {
+            heim::disk::partitions_physical().await?.boxed()
+        };
     futures::pin_mut!(partitions_stream);
 
+    // Non-physical partitions may be network mounts, which can hang, so their usage is fetched on
+    // separate threads, all at once, and collected afterwards under a single deadline.
+    let mut pending_disks: Vec<(DiskHarvest, Option<UsageReceiver>)> = Vec::new();
+
     while let Some(part) = partitions_stream.next().await {
         if let Ok(partition) = part {
             let name = get_device_name(&partition);
+            let file_system = partition.file_system().as_str().to_string();
 
             let mount_point = (partition
                 .mount_point()
@@ -80,75 +217,136 @@ pub async fn get_disk_usage(
                 .unwrap_or("Name Unavailable"))
             .to_string();
 
-            // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
-            //
-            // For implementation, we do this as follows:
-            // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
-            // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
-            // 3. Anything else is allowed.
-
-            let filter_check_map = [(disk_filter, &name), (mount_filter, &mount_point)];
-
-            // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
-            // immediately allow it!
-            let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
-                if let Some(filter) = filter {
-                    if !filter.is_list_ignored {
-                        for r in &filter.list {
-                            if r.is_match(text) {
-                                return true;
-                            }
-                        }
-                    }
-                }
-                false
-            });
-
-            let to_keep = if matches_allow_list {
-                true
-            } else {
-                // If it doesn't match an allow list, then check if it is denied.
-                // That is, if it matches in a reject filter, then reject.  Otherwise, we always keep it.
-                !filter_check_map.iter().any(|(filter, text)| {
-                    if let Some(filter) = filter {
-                        if filter.is_list_ignored {
-                            for r in &filter.list {
-                                if r.is_match(text) {
-                                    return true;
-                                }
-                            }
-                        }
-                    }
-                    false
-                })
-            };
+            if is_partition_kept(
+                &name,
+                &mount_point,
+                &file_system,
+                disk_filter,
+                mount_filter,
+                fs_filter,
+            ) {
+                let disk = DiskHarvest {
+                    name,
+                    mount_point,
+                    ..DiskHarvest::default()
+                };
 
-            if to_keep {
                 // The usage line can fail in some cases (for example, if you use Void Linux + LUKS,
                 // see https://github.com/ClementTsang/bottom/issues/419 for details).  As such, check
                 // it like this instead.
-                if let Ok(usage) = heim::disk::usage(partition.mount_point()).await {
-                    vec_disks.push(DiskHarvest {
-                        free_space: Some(usage.free().get::<heim::units::information::byte>()),
-                        used_space: Some(usage.used().get::<heim::units::information::byte>()),
-                        total_space: Some(usage.total().get::<heim::units::information::byte>()),
-                        mount_point,
-                        name,
-                    });
+                if partition.file_system().is_physical() {
+                    let usage = heim::disk::usage(partition.mount_point()).await.ok();
+                    vec_disks.push(with_usage(disk, usage));
                 } else {
-                    vec_disks.push(DiskHarvest {
-                        free_space: None,
-                        used_space: None,
-                        total_space: None,
-                        mount_point,
-                        name,
-                    });
+                    pending_disks.push((disk, spawn_usage_call(partition.mount_point())));
                 }
             }
         }
     }
 
+    let deadline = Instant::now() + USAGE_TIMEOUT;
+    for (disk, receiver) in pending_disks {
+        let usage = receiver.and_then(|receiver| {
+            receiver
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .ok()?
+                .ok()
+        });
+        vec_disks.push(with_usage(disk, usage));
+    }
+
     vec_disks.sort_by(|a, b| a.name.cmp(&b.name));
 
     Ok(Some(vec_disks))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_filter(is_list_ignored: bool, list: &[&str]) -> Option<Filter> {
+        Some(Filter {
+            is_list_ignored,
+            list: list
+                .iter()
+                .map(|regex| regex::Regex::new(regex).unwrap())
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_partition_fs_filter() {
+        let deny_tmpfs = get_filter(true, &["tmpfs"]);
+        assert!(!is_partition_kept(
+            "tmpfs",
+            "/tmp",
+            "tmpfs",
+            &None,
+            &None,
+            &deny_tmpfs
+        ));
+        assert!(is_partition_kept(
+            "/dev/sda1",
+            "/",
+            "ext4",
+            &None,
+            &None,
+            &deny_tmpfs
+        ));
+
+        let allow_ext4 = get_filter(false, &["ext4"]);
+        assert!(is_partition_kept(
+            "/dev/sda1",
+            "/",
+            "ext4",
+            &None,
+            &None,
+            &allow_ext4
+        ));
+
+        // Anything not denied is kept, even if an allow list exists and doesn't match.
+        assert!(is_partition_kept(
+            "tmpfs",
+            "/tmp",
+            "tmpfs",
+            &None,
+            &None,
+            &allow_ext4
+        ));
+    }
+
+    #[test]
+    fn test_partition_filter_allow_beats_deny() {
+        // Allowing a mount point keeps it even though its filesystem is denied...
+        let allow_mount = get_filter(false, &["^/run/user"]);
+        let deny_tmpfs = get_filter(true, &["tmpfs"]);
+        assert!(is_partition_kept(
+            "tmpfs",
+            "/run/user/1000",
+            "tmpfs",
+            &None,
+            &allow_mount,
+            &deny_tmpfs
+        ));
+        assert!(!is_partition_kept(
+            "tmpfs",
+            "/dev/shm",
+            "tmpfs",
+            &None,
+            &allow_mount,
+            &deny_tmpfs
+        ));
+
+        // ...and allowing a filesystem keeps it even though its mount point is denied.
+        let deny_mount = get_filter(true, &["^/mnt"]);
+        let allow_nfs = get_filter(false, &["nfs"]);
+        assert!(is_partition_kept(
+            "server:/export",
+            "/mnt/share",
+            "nfs4",
+            &None,
+            &deny_mount,
+            &allow_nfs
+        ));
+    }
+}
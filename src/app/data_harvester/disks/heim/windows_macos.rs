@@ -12,3 +12,8 @@ pub fn get_device_name(partition: &Partition) -> String {
         "Name Unavailable".to_string()
     }
 }
+
+/// Block device hierarchies are only resolved on Linux.
+pub fn get_block_devices(_actually_get: bool) -> Option<super::BlockDeviceMap> {
+    None
+}
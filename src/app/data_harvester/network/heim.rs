@@ -1,14 +1,17 @@
 //! Gets network data via heim.
 
-use super::NetworkHarvest;
-use std::time::Instant;
+use super::{get_rate, is_interface_kept, InterfaceHarvest, NetworkHarvest, PacketCounts};
+use std::{collections::HashMap, time::Instant};
 
 // FIXME: Eventually make it so that this thing also takes individual usage into account, so we can allow for showing per-interface!
 pub async fn get_network_data(
     prev_net_access_time: Instant, prev_net_rx: &mut u64, prev_net_tx: &mut u64,
-    curr_time: Instant, actually_get: bool, filter: &Option<crate::app::Filter>,
+    prev_packets: &mut PacketCounts, curr_time: Instant, actually_get: bool,
+    filter: &Option<crate::app::Filter>,
 ) -> crate::utils::error::Result<Option<NetworkHarvest>> {
     use futures::StreamExt;
+    #[cfg(target_os = "linux")]
+    use heim::net::os::linux::IoCountersExt;
 
     if !actually_get {
         return Ok(None);
@@ -18,33 +21,27 @@ pub async fn get_network_data(
     futures::pin_mut!(io_data);
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut total_packets = PacketCounts::default();
 
     while let Some(io) = io_data.next().await {
         if let Ok(io) = io {
-            let to_keep = if let Some(filter) = filter {
-                if filter.is_list_ignored {
-                    let mut ret = true;
-                    for r in &filter.list {
-                        if r.is_match(io.interface()) {
-                            ret = false;
-                            break;
-                        }
-                    }
-                    ret
-                } else {
-                    true
-                }
-            } else {
-                true
-            };
-
-            if to_keep {
+            if is_interface_kept(io.interface(), filter) {
                 // TODO: Use bytes as the default instead, perhaps?
                 // Since you might have to do a double conversion (bytes -> bits -> bytes) in some cases;
                 // but if you stick to bytes, then in the bytes, case, you do no conversion, and in the bits case,
                 // you only do one conversion...
                 total_rx += io.bytes_recv().get::<heim::units::information::bit>();
                 total_tx += io.bytes_sent().get::<heim::units::information::bit>();
+
+                total_packets.rx_packets += io.packets_recv();
+                total_packets.tx_packets += io.packets_sent();
+                total_packets.rx_errors += io.errors_recv();
+                total_packets.tx_errors += io.errors_sent();
+                total_packets.rx_drops += io.drop_recv();
+                #[cfg(target_os = "linux")]
+                {
+                    total_packets.tx_drops += io.drop_sent();
+                }
             }
         }
     }
@@ -60,12 +57,128 @@ pub async fn get_network_data(
         )
     };
 
+    let packets = total_packets.rates_since(prev_packets, elapsed_time);
+
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
+    *prev_packets = total_packets;
     Ok(Some(NetworkHarvest {
         rx,
         tx,
         total_rx,
         total_tx,
+        packets,
+        total_packets,
     }))
 }
+
+/// Gets details about each network interface, along with its current usage.
+pub async fn get_interface_data(
+    prev_net_access_time: Instant, prev_interface_totals: &mut HashMap<String, (u64, u64)>,
+    curr_time: Instant, actually_get: bool, filter: &Option<crate::app::Filter>,
+) -> crate::utils::error::Result<Option<Vec<InterfaceHarvest>>> {
+    use futures::StreamExt;
+
+    if !actually_get {
+        return Ok(None);
+    }
+
+    let elapsed_time = curr_time.duration_since(prev_net_access_time).as_secs_f64();
+    let mut interfaces: Vec<InterfaceHarvest> = Vec::new();
+    let mut interface_totals: HashMap<String, (u64, u64)> = HashMap::new();
+
+    let io_data = heim::net::io_counters().await?;
+    futures::pin_mut!(io_data);
+    while let Some(io) = io_data.next().await {
+        if let Ok(io) = io {
+            let name = io.interface();
+            if !is_interface_kept(name, filter) {
+                continue;
+            }
+
+            let total_rx = io.bytes_recv().get::<heim::units::information::bit>();
+            let total_tx = io.bytes_sent().get::<heim::units::information::bit>();
+            let (rx, tx) = match prev_interface_totals.get(name) {
+                Some((prev_rx, prev_tx)) => (
+                    get_rate(total_rx, *prev_rx, elapsed_time) as u64,
+                    get_rate(total_tx, *prev_tx, elapsed_time) as u64,
+                ),
+                None => (0, 0),
+            };
+            interface_totals.insert(name.to_string(), (total_rx, total_tx));
+
+            interfaces.push(InterfaceHarvest {
+                name: name.to_string(),
+                rx,
+                tx,
+                ..InterfaceHarvest::default()
+            });
+        }
+    }
+
+    let nic_data = heim::net::nic().await?;
+    futures::pin_mut!(nic_data);
+    while let Some(nic) = nic_data.next().await {
+        if let Ok(nic) = nic {
+            if let Some(interface) = interfaces.iter_mut().find(|i| i.name == nic.name()) {
+                match nic.address() {
+                    heim::net::Address::Inet(addr) => {
+                        interface.ipv4_addresses.push(addr.ip().to_string())
+                    }
+                    heim::net::Address::Inet6(addr) => {
+                        interface.ipv6_addresses.push(addr.ip().to_string())
+                    }
+                    heim::net::Address::Link(addr) => {
+                        interface.mac_address = Some(addr.to_string().to_lowercase())
+                    }
+                    _ => {}
+                }
+
+                if interface.link_state.is_none() {
+                    interface.link_state =
+                        Some(if nic.is_up() { "up" } else { "down" }.to_string());
+                }
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        for interface in &mut interfaces {
+            add_sysfs_details(interface);
+        }
+    }
+
+    *prev_interface_totals = interface_totals;
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Some(interfaces))
+}
+
+/// Fills in the link state, MTU, speed, and duplex of an interface from `/sys/class/net`.
+#[cfg(target_os = "linux")]
+fn add_sysfs_details(interface: &mut InterfaceHarvest) {
+    let path = format!("/sys/class/net/{}", interface.name);
+    let read = |file: &str| {
+        std::fs::read_to_string(format!("{}/{}", path, file))
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty() && s != "unknown")
+    };
+
+    if let Some(link_state) = read("operstate") {
+        interface.link_state = Some(link_state);
+    }
+    interface.mtu = read("mtu").and_then(|mtu| mtu.parse().ok());
+
+    // Speed is -1 (or unreadable) if the link is down or the driver doesn't report it.
+    interface.speed_mbps = read("speed")
+        .and_then(|speed| speed.parse::<i64>().ok())
+        .filter(|speed| *speed > 0)
+        .map(|speed| speed as u64);
+    interface.duplex = read("duplex");
+
+    if let Some(mac_address) = read("address") {
+        interface.mac_address = Some(mac_address);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! For Linux and macOS, this is handled by Heim.
 //! For Windows, this is handled by sysinfo.
+//!
+//! Wireless link quality is only supported on Linux.
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos"))] {
@@ -13,18 +15,113 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(target_os = "linux")]
+pub mod wireless;
+
+#[derive(Default, Clone, Debug)]
+/// Details about a single network interface.  Rates are in bits per second.
+pub struct InterfaceHarvest {
+    pub name: String,
+    pub link_state: Option<String>,
+    pub mtu: Option<u64>,
+    pub speed_mbps: Option<u64>,
+    pub duplex: Option<String>,
+    pub mac_address: Option<String>,
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub rx: u64,
+    pub tx: u64,
+}
+
+#[derive(Default, Clone, Debug)]
+/// The link quality of a wireless interface.  Levels are in dBm, and are `None` if the interface
+/// is not connected or the driver does not report them.
+pub struct WirelessHarvest {
+    pub name: String,
+    pub link_quality: Option<f64>,
+    pub signal_level: Option<f64>,
+    pub noise_level: Option<f64>,
+}
+
+/// Returns whether an interface should be shown, given the network filter.
+fn is_interface_kept(name: &str, filter: &Option<crate::app::Filter>) -> bool {
+    if let Some(filter) = filter {
+        let mut ret = filter.is_list_ignored;
+        for r in &filter.list {
+            if r.is_match(name) {
+                ret = !filter.is_list_ignored;
+                break;
+            }
+        }
+        ret
+    } else {
+        true
+    }
+}
+
+/// Returns the per-second rate of a counter, given the elapsed time in seconds.
+fn get_rate(curr: u64, prev: u64, elapsed_time: f64) -> f64 {
+    if elapsed_time == 0.0 {
+        0.0
+    } else {
+        curr.saturating_sub(prev) as f64 / elapsed_time
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+/// Packet, error, and drop counts, summed over all interfaces that are not filtered out.
+pub struct PacketCounts {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_drops: u64,
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+/// Packet, error, and drop rates per second, summed over all interfaces that are not filtered out.
+pub struct PacketRates {
+    pub rx_packets: f64,
+    pub tx_packets: f64,
+    pub rx_errors: f64,
+    pub tx_errors: f64,
+    pub rx_drops: f64,
+    pub tx_drops: f64,
+}
+
+impl PacketCounts {
+    /// Returns the per-second rates between `prev` and these counts, given the elapsed time in
+    /// seconds.
+    pub fn rates_since(&self, prev: &PacketCounts, elapsed_time: f64) -> PacketRates {
+        let rate = |curr: u64, prev: u64| get_rate(curr, prev, elapsed_time);
+
+        PacketRates {
+            rx_packets: rate(self.rx_packets, prev.rx_packets),
+            tx_packets: rate(self.tx_packets, prev.tx_packets),
+            rx_errors: rate(self.rx_errors, prev.rx_errors),
+            tx_errors: rate(self.tx_errors, prev.tx_errors),
+            rx_drops: rate(self.rx_drops, prev.rx_drops),
+            tx_drops: rate(self.tx_drops, prev.tx_drops),
+        }
+    }
+}
+
 #[derive(Default, Clone, Debug)]
-/// All units in bits.
+/// All units in bits, except for packets, which are in packets (per second for `packets`).
 pub struct NetworkHarvest {
     pub rx: u64,
     pub tx: u64,
     pub total_rx: u64,
     pub total_tx: u64,
+    pub packets: PacketRates,
+    pub total_packets: PacketCounts,
 }
 
 impl NetworkHarvest {
     pub fn first_run_cleanup(&mut self) {
         self.rx = 0;
         self.tx = 0;
+        self.packets = PacketRates::default();
     }
 }
@@ -1,11 +1,11 @@
 //! Gets network data via sysinfo.
 
-use super::NetworkHarvest;
-use std::time::Instant;
+use super::{get_rate, is_interface_kept, InterfaceHarvest, NetworkHarvest, PacketCounts};
+use std::{collections::HashMap, time::Instant};
 
 pub async fn get_network_data(
     sys: &sysinfo::System, prev_net_access_time: Instant, prev_net_rx: &mut u64,
-    prev_net_tx: &mut u64, curr_time: Instant, actually_get: bool,
+    prev_net_tx: &mut u64, prev_packets: &mut PacketCounts, curr_time: Instant, actually_get: bool,
     filter: &Option<crate::app::Filter>,
 ) -> crate::utils::error::Result<Option<NetworkHarvest>> {
     use sysinfo::{NetworkExt, SystemExt};
@@ -16,25 +16,18 @@ pub async fn get_network_data(
 
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut total_packets = PacketCounts::default();
 
     let networks = sys.networks();
     for (name, network) in networks {
-        let to_keep = if let Some(filter) = filter {
-            let mut ret = filter.is_list_ignored;
-            for r in &filter.list {
-                if r.is_match(name) {
-                    ret = !filter.is_list_ignored;
-                    break;
-                }
-            }
-            ret
-        } else {
-            true
-        };
-
-        if to_keep {
+        if is_interface_kept(name, filter) {
             total_rx += network.total_received() * 8;
             total_tx += network.total_transmitted() * 8;
+
+            total_packets.rx_packets += network.total_packets_received();
+            total_packets.tx_packets += network.total_packets_transmitted();
+            total_packets.rx_errors += network.total_errors_on_received();
+            total_packets.tx_errors += network.total_errors_on_transmitted();
         }
     }
 
@@ -49,12 +42,64 @@ pub async fn get_network_data(
         )
     };
 
+    let packets = total_packets.rates_since(prev_packets, elapsed_time);
+
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
+    *prev_packets = total_packets;
     Ok(Some(NetworkHarvest {
         rx,
         tx,
         total_rx,
         total_tx,
+        packets,
+        total_packets,
     }))
 }
+
+/// Gets the current usage of each network interface.  Other details are not available via
+/// sysinfo.
+pub async fn get_interface_data(
+    sys: &sysinfo::System, prev_net_access_time: Instant,
+    prev_interface_totals: &mut HashMap<String, (u64, u64)>, curr_time: Instant,
+    actually_get: bool, filter: &Option<crate::app::Filter>,
+) -> crate::utils::error::Result<Option<Vec<InterfaceHarvest>>> {
+    use sysinfo::{NetworkExt, SystemExt};
+
+    if !actually_get {
+        return Ok(None);
+    }
+
+    let elapsed_time = curr_time.duration_since(prev_net_access_time).as_secs_f64();
+    let mut interfaces: Vec<InterfaceHarvest> = Vec::new();
+    let mut interface_totals: HashMap<String, (u64, u64)> = HashMap::new();
+
+    for (name, network) in sys.networks() {
+        if !is_interface_kept(name, filter) {
+            continue;
+        }
+
+        let total_rx = network.total_received() * 8;
+        let total_tx = network.total_transmitted() * 8;
+        let (rx, tx) = match prev_interface_totals.get(name) {
+            Some((prev_rx, prev_tx)) => (
+                get_rate(total_rx, *prev_rx, elapsed_time) as u64,
+                get_rate(total_tx, *prev_tx, elapsed_time) as u64,
+            ),
+            None => (0, 0),
+        };
+        interface_totals.insert(name.to_string(), (total_rx, total_tx));
+
+        interfaces.push(InterfaceHarvest {
+            name: name.to_string(),
+            rx,
+            tx,
+            ..InterfaceHarvest::default()
+        });
+    }
+
+    *prev_interface_totals = interface_totals;
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Some(interfaces))
+}
@@ -0,0 +1,92 @@
+//! Gets wireless link quality from `/proc/net/wireless` and `/sys/class/net`.
+
+use super::WirelessHarvest;
+
+/// A noise level the driver reports when it does not actually measure noise.
+const NO_NOISE_LEVEL: f64 = -256.0;
+
+pub fn get_wireless_data(
+    actually_get: bool, filter: &Option<crate::app::Filter>,
+) -> Option<Vec<WirelessHarvest>> {
+    if !actually_get {
+        return None;
+    }
+
+    let mut wireless: Vec<WirelessHarvest> = std::fs::read_to_string("/proc/net/wireless")
+        .map(|contents| {
+            contents
+                .lines()
+                .skip(2) // Skip the two header lines.
+                .filter_map(parse_wireless_line)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Interfaces that are wireless but not currently associated may be missing from
+    // /proc/net/wireless, but still have a wireless directory in sysfs.
+    if let Ok(entries) = std::fs::read_dir("/sys/class/net") {
+        for entry in entries.flatten() {
+            if entry.path().join("wireless").exists() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !wireless.iter().any(|w| w.name == name) {
+                    wireless.push(WirelessHarvest {
+                        name,
+                        ..WirelessHarvest::default()
+                    });
+                }
+            }
+        }
+    }
+
+    wireless.retain(|w| super::is_interface_kept(&w.name, filter));
+    wireless.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Some(wireless)
+}
+
+/// Parses a line of `/proc/net/wireless`, which looks like:
+///
+/// `wlan0: 0000   54.  -56.  -256.        0      0      0      0      0        0`
+fn parse_wireless_line(line: &str) -> Option<WirelessHarvest> {
+    let (name, values) = line.split_once(':')?;
+    let mut values = values.split_whitespace().skip(1); // Skip the status.
+    let mut next_value = || {
+        values
+            .next()
+            .and_then(|value| value.trim_end_matches('.').parse::<f64>().ok())
+    };
+
+    let link_quality = next_value();
+    let signal_level = next_value();
+    let noise_level = next_value().filter(|noise| *noise != NO_NOISE_LEVEL);
+
+    Some(WirelessHarvest {
+        name: name.trim().to_string(),
+        link_quality,
+        signal_level,
+        noise_level,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wireless_line_parse() {
+        let wireless = parse_wireless_line(
+            " wlan0: 0000   54.  -56.  -256.        0      0      0      0      0        0",
+        )
+        .unwrap();
+        assert_eq!(wireless.name, "wlan0");
+        assert_eq!(wireless.link_quality, Some(54.0));
+        assert_eq!(wireless.signal_level, Some(-56.0));
+        assert_eq!(wireless.noise_level, None);
+
+        let wireless = parse_wireless_line("wlp3s0: 0000   70.  -40.  -95.   0 0 0 0 0 0").unwrap();
+        assert_eq!(wireless.name, "wlp3s0");
+        assert_eq!(wireless.noise_level, Some(-95.0));
+
+        assert!(parse_wireless_line("Inter-| sta-|   Quality        |").is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! Process data collection for Linux.
 
 use std::collections::hash_map::Entry;
+use std::time::{Duration, Instant};
 
 use crate::utils::error::{self, BottomError};
 use crate::Pid;
@@ -9,7 +10,7 @@ use super::ProcessHarvest;
 
 use sysinfo::ProcessStatus;
 
-use procfs::process::{Process, Stat};
+use procfs::process::{LimitValue, Process, Stat};
 
 use fxhash::{FxHashMap, FxHashSet};
 
@@ -17,11 +18,48 @@ use fxhash::{FxHashMap, FxHashSet};
 /// If it's equal or greater, then we instead refer to the command for the name.
 const MAX_STAT_NAME_LEN: usize = 15;
 
+/// The per-process flag the kernel sets on its own threads, see `include/linux/sched.h`.
+const PF_KTHREAD: u32 = 0x0020_0000;
+
+/// The PID of `kthreadd`, which every kernel thread is spawned from.
+const KTHREADD_PID: Pid = 2;
+
+/// How often to re-check whether a process is running deleted code, as reading its memory map is
+/// expensive compared to the rest of a process' details.
+const DELETED_CODE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often to re-read a process' OOM score and adjustment.  These only drift as memory usage
+/// changes, so there's no need to read two more files per process every tick.
+const OOM_SCORE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often to re-check which systemd unit a process belongs to.  Processes rarely move between
+/// cgroups, and parsing `/proc/<PID>/cgroup` for every process every tick adds up.
+const UNIT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often to re-count a process' open file descriptors and re-read its limit.  Counting means
+/// listing `/proc/<PID>/fd`, which is slow for processes with many files open.
+const FD_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
 #[derive(Debug, Clone)]
 pub struct PrevProcDetails {
     pub total_read_bytes: u64,
     pub total_write_bytes: u64,
+    pub minor_faults: u64,
+    pub major_faults: u64,
+    pub voluntary_switches: u64,
+    pub involuntary_switches: u64,
     pub cpu_time: u64,
+    pub is_exe_deleted: bool,
+    pub has_deleted_libraries: bool,
+    pub deleted_code_checked: Option<Instant>,
+    pub oom_score: Option<u32>,
+    pub oom_score_adj: Option<i32>,
+    pub oom_score_checked: Option<Instant>,
+    pub unit: Option<String>,
+    pub unit_checked: Option<Instant>,
+    pub fd_count: Option<u64>,
+    pub fd_limit: Option<u64>,
+    pub fds_checked: Option<Instant>,
     pub process: Process,
 }
 
@@ -30,10 +68,101 @@ impl PrevProcDetails {
         Ok(Self {
             total_read_bytes: 0,
             total_write_bytes: 0,
+            minor_faults: 0,
+            major_faults: 0,
+            voluntary_switches: 0,
+            involuntary_switches: 0,
             cpu_time: 0,
+            is_exe_deleted: false,
+            has_deleted_libraries: false,
+            deleted_code_checked: None,
+            oom_score: None,
+            oom_score_adj: None,
+            oom_score_checked: None,
+            unit: None,
+            unit_checked: None,
+            fd_count: None,
+            fd_limit: None,
+            fds_checked: None,
             process: Process::new(pid)?,
         })
     }
+
+    /// Re-checks whether the process is running a deleted executable or library, if it hasn't been
+    /// checked within [`DELETED_CODE_CHECK_INTERVAL`].
+    fn update_deleted_code(&mut self) {
+        if !is_stale(self.deleted_code_checked, DELETED_CODE_CHECK_INTERVAL) {
+            return;
+        }
+
+        // Both of these also need the same permissions as reading another user's process memory.
+        self.is_exe_deleted = self
+            .process
+            .exe()
+            .map_or(false, |exe| exe.to_string_lossy().ends_with(" (deleted)"));
+        self.has_deleted_libraries =
+            std::fs::read_to_string(format!("/proc/{}/maps", self.process.pid))
+                .map_or(false, |maps| has_deleted_code_mapping(&maps));
+        self.deleted_code_checked = Some(Instant::now());
+    }
+
+    /// Re-reads the process' OOM score and adjustment, if they haven't been read within
+    /// [`OOM_SCORE_CHECK_INTERVAL`].
+    fn update_oom_score(&mut self) {
+        if !is_stale(self.oom_score_checked, OOM_SCORE_CHECK_INTERVAL) {
+            return;
+        }
+
+        self.oom_score = self.process.oom_score().ok();
+        self.oom_score_adj =
+            std::fs::read_to_string(format!("/proc/{}/oom_score_adj", self.process.pid))
+                .ok()
+                .and_then(|oom_score_adj| oom_score_adj.trim().parse::<i32>().ok());
+        self.oom_score_checked = Some(Instant::now());
+    }
+
+    /// Re-checks the systemd unit of the process, if it hasn't been checked within
+    /// [`UNIT_CHECK_INTERVAL`].
+    fn update_unit(&mut self) {
+        if !is_stale(self.unit_checked, UNIT_CHECK_INTERVAL) {
+            return;
+        }
+
+        // systemd tracks units with its own hierarchy under cgroups v1, and the unified one under v2.
+        self.unit = self.process.cgroups().ok().and_then(|cgroups| {
+            cgroups
+                .iter()
+                .find(|cgroup| cgroup.controllers.iter().any(|c| c == "name=systemd"))
+                .or_else(|| cgroups.iter().find(|cgroup| cgroup.hierarchy == 0))
+                .and_then(|cgroup| get_systemd_unit(&cgroup.pathname))
+        });
+        self.unit_checked = Some(Instant::now());
+    }
+
+    /// Re-counts the process' open file descriptors and re-reads its limit, if they haven't been
+    /// read within [`FD_CHECK_INTERVAL`].
+    fn update_fds(&mut self) {
+        if !is_stale(self.fds_checked, FD_CHECK_INTERVAL) {
+            return;
+        }
+
+        // Both of these need the same permissions as reading another user's process memory.
+        self.fd_count = self.process.fd_count().ok().map(|count| count as u64);
+        self.fd_limit =
+            self.process
+                .limits()
+                .ok()
+                .and_then(|limits| match limits.max_open_files.soft_limit {
+                    LimitValue::Value(limit) => Some(limit),
+                    LimitValue::Unlimited => None,
+                });
+        self.fds_checked = Some(Instant::now());
+    }
+}
+
+/// Returns whether a cached value last checked at `checked` is due to be checked again.
+fn is_stale(checked: Option<Instant>, interval: Duration) -> bool {
+    checked.map_or(true, |checked| checked.elapsed() >= interval)
 }
 
 fn calculate_idle_values(line: String) -> (f64, f64) {
@@ -116,6 +245,93 @@ fn get_linux_cpu_usage(
     }
 }
 
+/// Formats a list of inclusive CPU ranges in the same style as `Cpus_allowed_list`, e.g. `0-3,6`.
+fn format_cpu_list(ranges: &[(u32, u32)]) -> String {
+    ranges
+        .iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Returns the systemd unit owning a cgroup path, e.g. `sshd.service` for `/system.slice/sshd.service`.
+/// The innermost service or scope is preferred over the slices containing it.
+fn get_systemd_unit(cgroup_path: &str) -> Option<String> {
+    let mut unit = None;
+    let mut slice = None;
+    for component in cgroup_path.split('/') {
+        if component.ends_with(".service") || component.ends_with(".scope") {
+            unit = Some(component);
+        } else if component.ends_with(".slice") {
+            slice = Some(component);
+        }
+    }
+
+    unit.or(slice).map(|unit| unit.to_string())
+}
+
+/// Returns whether a process is a kernel thread, given its `/proc/<PID>/stat` flags, PID, and
+/// parent PID.  `kthreadd` and its direct children are counted even without `PF_KTHREAD` set.
+fn is_kernel_thread(flags: u32, pid: Pid, ppid: Pid) -> bool {
+    flags & PF_KTHREAD != 0 || pid == KTHREADD_PID || ppid == KTHREADD_PID
+}
+
+/// Returns the name of a controlling terminal given its `tty_nr` device number, e.g. `pts/0` or
+/// `tty1`, in the same style as `ps`.  Terminals without a well-known name are shown as
+/// `major:minor`.
+fn get_tty_name(tty_nr: i32) -> Option<String> {
+    if tty_nr == 0 {
+        return None;
+    }
+
+    // The minor number is split between bits 31 to 20 and 7 to 0, and the major is bits 15 to 8.
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+
+    Some(match major {
+        4 if minor < 64 => format!("tty{}", minor),
+        4 => format!("ttyS{}", minor - 64),
+        136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+        _ => format!("{}:{}", major, minor),
+    })
+}
+
+/// Returns whether any executable mapping in a `/proc/<pid>/maps` listing points to a file that
+/// has since been deleted, which is what happens to old libraries after a package upgrade.
+/// In-memory files (such as `memfd`s and shared memory) are not counted.
+fn has_deleted_code_mapping(maps: &str) -> bool {
+    maps.lines().any(|line| {
+        let mut fields = line.split_whitespace();
+        let is_executable = fields
+            .nth(1)
+            .map_or(false, |permissions| permissions.contains('x'));
+        let path = fields.nth(3).unwrap_or_default();
+
+        is_executable
+            && line.ends_with(" (deleted)")
+            && path.starts_with('/')
+            && !path.starts_with("/memfd:")
+            && !path.starts_with("/dev/")
+            && !path.starts_with("/SYSV")
+    })
+}
+
+/// Returns the per-second rate of a counter since the previous harvest.  Counters that went
+/// backwards, such as when a PID is reused, are treated as not having changed.
+fn get_rate(current: u64, previous: u64, time_difference_in_secs: u64) -> f64 {
+    if time_difference_in_secs == 0 {
+        0.0
+    } else {
+        current.saturating_sub(previous) as f64 / time_difference_in_secs as f64
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn read_proc(
     prev_proc: &PrevProcDetails, stat: &Stat, cpu_usage: f64, cpu_fraction: f64,
@@ -171,8 +387,9 @@ fn read_proc(
 
     // This can fail if permission is denied!
 
+    let io = process.io().ok();
     let (total_read_bytes, total_write_bytes, read_bytes_per_sec, write_bytes_per_sec) =
-        if let Ok(io) = process.io() {
+        if let Some(io) = &io {
             let total_read_bytes = io.read_bytes;
             let total_write_bytes = io.write_bytes;
 
@@ -199,6 +416,54 @@ fn read_proc(
             (0, 0, 0, 0)
         };
 
+    let (read_chars, write_chars, read_syscalls, write_syscalls, cancelled_write_bytes) =
+        if let Some(io) = &io {
+            (
+                io.rchar,
+                io.wchar,
+                io.syscr,
+                io.syscw,
+                io.cancelled_write_bytes,
+            )
+        } else {
+            (0, 0, 0, 0, 0)
+        };
+
+    let rate_since_prev =
+        |current: u64, previous: u64| get_rate(current, previous, time_difference_in_secs);
+
+    let minor_faults = stat.minflt;
+    let major_faults = stat.majflt;
+    let minor_faults_per_sec = rate_since_prev(minor_faults, prev_proc.minor_faults);
+    let major_faults_per_sec = rate_since_prev(major_faults, prev_proc.major_faults);
+
+    let status = process.status().ok();
+    let (voluntary_switches, involuntary_switches) = status
+        .as_ref()
+        .map(|status| {
+            (
+                status.voluntary_ctxt_switches.unwrap_or(0),
+                status.nonvoluntary_ctxt_switches.unwrap_or(0),
+            )
+        })
+        .unwrap_or((0, 0));
+    let voluntary_switches_per_sec =
+        rate_since_prev(voluntary_switches, prev_proc.voluntary_switches);
+    let involuntary_switches_per_sec =
+        rate_since_prev(involuntary_switches, prev_proc.involuntary_switches);
+
+    let cpus_allowed_list = status
+        .and_then(|status| status.cpus_allowed_list)
+        .map(|ranges| format_cpu_list(&ranges))
+        .unwrap_or_default();
+    let last_cpu = stat.processor.map(|processor| processor as u32);
+
+    let tty = get_tty_name(stat.tty_nr);
+    let session_id = Some(stat.session);
+    let process_group_id = Some(stat.pgrp);
+
+    let is_kernel_thread = is_kernel_thread(stat.flags, stat.pid, stat.ppid);
+
     let uid = Some(process.owner);
 
     Ok((
@@ -216,6 +481,32 @@ fn read_proc(
             total_write_bytes,
             process_state,
             process_state_char,
+            read_chars,
+            write_chars,
+            read_syscalls,
+            write_syscalls,
+            cancelled_write_bytes,
+            cpus_allowed_list,
+            last_cpu,
+            oom_score: prev_proc.oom_score,
+            oom_score_adj: prev_proc.oom_score_adj,
+            unit: prev_proc.unit.clone(),
+            is_kernel_thread,
+            minor_faults,
+            minor_faults_per_sec,
+            major_faults,
+            major_faults_per_sec,
+            voluntary_switches,
+            voluntary_switches_per_sec,
+            involuntary_switches,
+            involuntary_switches_per_sec,
+            fd_count: prev_proc.fd_count,
+            fd_limit: prev_proc.fd_limit,
+            is_exe_deleted: prev_proc.is_exe_deleted,
+            has_deleted_libraries: prev_proc.has_deleted_libraries,
+            tty,
+            session_id,
+            process_group_id,
             uid,
         },
         new_process_times,
@@ -248,6 +539,11 @@ pub fn get_process_data(
                         };
 
                         if let Some(prev_proc_details) = pid_mapping.get_mut(&pid) {
+                            prev_proc_details.update_deleted_code();
+                            prev_proc_details.update_oom_score();
+                            prev_proc_details.update_unit();
+                            prev_proc_details.update_fds();
+
                             let stat;
                             let stat_live;
                             if fresh {
@@ -274,6 +570,12 @@ pub fn get_process_data(
                                     process_harvest.total_read_bytes;
                                 prev_proc_details.total_write_bytes =
                                     process_harvest.total_write_bytes;
+                                prev_proc_details.minor_faults = process_harvest.minor_faults;
+                                prev_proc_details.major_faults = process_harvest.major_faults;
+                                prev_proc_details.voluntary_switches =
+                                    process_harvest.voluntary_switches;
+                                prev_proc_details.involuntary_switches =
+                                    process_harvest.involuntary_switches;
 
                                 pids_to_clear.remove(&pid);
                                 return Some(process_harvest);
@@ -302,6 +604,108 @@ pub fn get_process_data(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_rate() {
+        assert_eq!(get_rate(150, 100, 1), 50.0);
+        assert_eq!(get_rate(101, 100, 2), 0.5);
+        assert_eq!(get_rate(100, 150, 1), 0.0);
+        assert_eq!(get_rate(150, 100, 0), 0.0);
+    }
+
+    #[test]
+    fn test_deleted_code_is_cached() {
+        let mut prev_proc = PrevProcDetails::new(std::process::id() as Pid).unwrap();
+        prev_proc.update_deleted_code();
+        assert!(prev_proc.deleted_code_checked.is_some());
+        assert!(!prev_proc.is_exe_deleted);
+
+        // A recent check is reused as is...
+        prev_proc.is_exe_deleted = true;
+        prev_proc.update_deleted_code();
+        assert!(prev_proc.is_exe_deleted);
+
+        // ...while a stale one is redone.
+        prev_proc.deleted_code_checked = None;
+        prev_proc.update_deleted_code();
+        assert!(!prev_proc.is_exe_deleted);
+    }
+
+    #[test]
+    fn test_oom_score_is_cached() {
+        let mut prev_proc = PrevProcDetails::new(std::process::id() as Pid).unwrap();
+        prev_proc.update_oom_score();
+        assert!(prev_proc.oom_score_checked.is_some());
+        let oom_score_adj = prev_proc.oom_score_adj;
+        assert!(oom_score_adj.is_some());
+
+        // A recent read is reused as is...
+        prev_proc.oom_score_adj = None;
+        prev_proc.update_oom_score();
+        assert_eq!(prev_proc.oom_score_adj, None);
+
+        // ...while a stale one is redone.
+        prev_proc.oom_score_checked = None;
+        prev_proc.update_oom_score();
+        assert_eq!(prev_proc.oom_score_adj, oom_score_adj);
+    }
+
+    #[test]
+    fn test_unit_is_cached() {
+        let mut prev_proc = PrevProcDetails::new(std::process::id() as Pid).unwrap();
+        prev_proc.update_unit();
+        assert!(prev_proc.unit_checked.is_some());
+        let unit = prev_proc.unit.clone();
+
+        // A recent check is reused as is...
+        prev_proc.unit = Some("cached.service".to_string());
+        prev_proc.update_unit();
+        assert_eq!(prev_proc.unit.as_deref(), Some("cached.service"));
+
+        // ...while a stale one is redone.
+        prev_proc.unit_checked = None;
+        prev_proc.update_unit();
+        assert_eq!(prev_proc.unit, unit);
+    }
+
+    #[test]
+    fn test_fds_are_cached() {
+        let mut prev_proc = PrevProcDetails::new(std::process::id() as Pid).unwrap();
+        prev_proc.update_fds();
+        assert!(prev_proc.fds_checked.is_some());
+        assert!(prev_proc.fd_count.is_some());
+
+        // A recent count is reused as is...
+        prev_proc.fd_count = None;
+        prev_proc.update_fds();
+        assert_eq!(prev_proc.fd_count, None);
+
+        // ...while a stale one is redone.
+        prev_proc.fds_checked = None;
+        prev_proc.update_fds();
+        assert!(prev_proc.fd_count.is_some());
+    }
+
+    #[test]
+    fn test_read_proc_rates_since_prev() {
+        let mut prev_proc = PrevProcDetails::new(std::process::id() as Pid).unwrap();
+        let stat = prev_proc.process.stat().unwrap();
+
+        // Pretend we saw three fewer minor faults last time, and a counter that has since reset.
+        prev_proc.minor_faults = stat.minflt.saturating_sub(3);
+        prev_proc.major_faults = stat.majflt;
+        prev_proc.voluntary_switches = u64::MAX;
+
+        let (process_harvest, _) =
+            read_proc(&prev_proc, &stat, 0.0, 0.0, false, 2, 1024 * 1024).unwrap();
+
+        assert_eq!(
+            process_harvest.minor_faults_per_sec,
+            (stat.minflt - prev_proc.minor_faults) as f64 / 2.0
+        );
+        assert_eq!(process_harvest.major_faults_per_sec, 0.0);
+        assert_eq!(process_harvest.voluntary_switches_per_sec, 0.0);
+    }
+
     #[test]
     fn test_proc_cpu_parse() {
         assert_eq!(
@@ -340,4 +744,80 @@ mod tests {
             "Failed to properly calculate idle/non-idle for /proc/stat CPU with 10 values"
         );
     }
+
+    #[test]
+    fn test_get_systemd_unit() {
+        assert_eq!(
+            get_systemd_unit("/system.slice/sshd.service"),
+            Some("sshd.service".to_string())
+        );
+        assert_eq!(
+            get_systemd_unit("/init.scope"),
+            Some("init.scope".to_string())
+        );
+        assert_eq!(
+            get_systemd_unit(
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/app-foo.scope"
+            ),
+            Some("app-foo.scope".to_string())
+        );
+        assert_eq!(
+            get_systemd_unit("/user.slice/user-1000.slice"),
+            Some("user-1000.slice".to_string())
+        );
+        assert_eq!(get_systemd_unit("/"), None);
+        assert_eq!(get_systemd_unit("/docker/0123456789ab"), None);
+    }
+
+    #[test]
+    fn test_is_kernel_thread() {
+        // kthreadd itself and anything it spawns.
+        assert!(is_kernel_thread(PF_KTHREAD, KTHREADD_PID, 0));
+        assert!(is_kernel_thread(PF_KTHREAD, 42, KTHREADD_PID));
+        assert!(is_kernel_thread(0, 42, KTHREADD_PID));
+
+        // Kernel threads that aren't direct children of kthreadd are still caught by the flag.
+        assert!(is_kernel_thread(PF_KTHREAD | 0x40, 42, 7));
+
+        // Regular processes, including init.
+        assert!(!is_kernel_thread(0, 1, 0));
+        assert!(!is_kernel_thread(0x0040_0100, 1234, 1));
+    }
+
+    #[test]
+    fn test_get_tty_name() {
+        assert_eq!(get_tty_name(0), None);
+        assert_eq!(get_tty_name(0x0401), Some("tty1".to_string()));
+        assert_eq!(get_tty_name(0x0440), Some("ttyS0".to_string()));
+        assert_eq!(get_tty_name(0x8802), Some("pts/2".to_string()));
+        assert_eq!(get_tty_name(0x0010_8800), Some("pts/256".to_string()));
+        assert_eq!(get_tty_name(0x0500), Some("5:0".to_string()));
+    }
+
+    #[test]
+    fn test_has_deleted_code_mapping() {
+        let maps = "\
+55d5c0a00000-55d5c0a2c000 r--p 00000000 fd:01 1234  /usr/bin/foo
+7f0e0c000000-7f0e0c021000 rw-p 00000000 00:00 0
+7f0e0d200000-7f0e0d3b5000 r-xp 00028000 fd:01 5678  /usr/lib/libc.so.6
+7ffd5e9f0000-7ffd5ea11000 rw-p 00000000 00:00 0  [stack]";
+        assert!(!has_deleted_code_mapping(maps));
+
+        let maps = "\
+7f0e0d200000-7f0e0d3b5000 r-xp 00028000 fd:01 5678  /usr/lib/libc.so.6 (deleted)";
+        assert!(has_deleted_code_mapping(maps));
+
+        let maps = "\
+7f0e0c000000-7f0e0c021000 rw-s 00000000 00:01 91  /dev/shm/foo (deleted)
+7f0e0d200000-7f0e0d3b5000 r-xp 00000000 00:01 92  /memfd:jit (deleted)
+7f0e0e200000-7f0e0e3b5000 r--p 00000000 fd:01 93  /var/cache/foo.db (deleted)";
+        assert!(!has_deleted_code_mapping(maps));
+    }
+
+    #[test]
+    fn test_format_cpu_list() {
+        assert_eq!(format_cpu_list(&[]), "");
+        assert_eq!(format_cpu_list(&[(0, 0)]), "0");
+        assert_eq!(format_cpu_list(&[(0, 3), (6, 6), (8, 11)]), "0-3,6,8-11");
+    }
 }
@@ -23,7 +23,7 @@ cfg_if::cfg_if! {
     }
 }
 
-use crate::Pid;
+use crate::{utils::error::BottomError, Pid};
 
 // TODO: Add value so we know if it's sorted ascending or descending by default?
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -41,6 +41,26 @@ pub enum ProcessSorting {
     State,
     User,
     Count,
+    ReadChars,
+    WriteChars,
+    ReadSyscalls,
+    WriteSyscalls,
+    CancelledWrite,
+    Affinity,
+    LastCpu,
+    OomScore,
+    OomScoreAdj,
+    Unit,
+    MinorFaults,
+    MajorFaults,
+    VoluntarySwitches,
+    InvoluntarySwitches,
+    FdCount,
+    FdPercent,
+    DeletedCode,
+    Tty,
+    SessionId,
+    ProcessGroupId,
 }
 
 impl std::fmt::Display for ProcessSorting {
@@ -62,6 +82,26 @@ impl std::fmt::Display for ProcessSorting {
                 ProcessSorting::Pid => "PID",
                 ProcessSorting::Count => "Count",
                 ProcessSorting::User => "User",
+                ProcessSorting::ReadChars => "RChar",
+                ProcessSorting::WriteChars => "WChar",
+                ProcessSorting::ReadSyscalls => "SysCR",
+                ProcessSorting::WriteSyscalls => "SysCW",
+                ProcessSorting::CancelledWrite => "CWrite",
+                ProcessSorting::Affinity => "Affinity",
+                ProcessSorting::LastCpu => "CPU#",
+                ProcessSorting::OomScore => "OOM",
+                ProcessSorting::OomScoreAdj => "OOMAdj",
+                ProcessSorting::Unit => "Unit",
+                ProcessSorting::MinorFaults => "MinFlt/s",
+                ProcessSorting::MajorFaults => "MajFlt/s",
+                ProcessSorting::VoluntarySwitches => "VCSw/s",
+                ProcessSorting::InvoluntarySwitches => "ICSw/s",
+                ProcessSorting::FdCount => "FDs",
+                ProcessSorting::FdPercent => "FD%",
+                ProcessSorting::DeletedCode => "Deleted",
+                ProcessSorting::Tty => "TTY",
+                ProcessSorting::SessionId => "SID",
+                ProcessSorting::ProcessGroupId => "PGID",
             }
         )
     }
@@ -73,6 +113,64 @@ impl Default for ProcessSorting {
     }
 }
 
+impl std::str::FromStr for ProcessSorting {
+    type Err = BottomError;
+
+    /// Parses the name of an optional process column, as used in the `process_columns` option.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rchar" => Ok(ProcessSorting::ReadChars),
+            "wchar" => Ok(ProcessSorting::WriteChars),
+            "syscr" => Ok(ProcessSorting::ReadSyscalls),
+            "syscw" => Ok(ProcessSorting::WriteSyscalls),
+            "cancelled_write_bytes" => Ok(ProcessSorting::CancelledWrite),
+            "affinity" | "cpus_allowed_list" => Ok(ProcessSorting::Affinity),
+            "last_cpu" | "processor" => Ok(ProcessSorting::LastCpu),
+            "oom_score" => Ok(ProcessSorting::OomScore),
+            "oom_score_adj" => Ok(ProcessSorting::OomScoreAdj),
+            "unit" | "systemd_unit" => Ok(ProcessSorting::Unit),
+            "minflt" => Ok(ProcessSorting::MinorFaults),
+            "majflt" => Ok(ProcessSorting::MajorFaults),
+            "vcsw" | "voluntary_ctxt_switches" => Ok(ProcessSorting::VoluntarySwitches),
+            "ivcsw" | "nonvoluntary_ctxt_switches" => Ok(ProcessSorting::InvoluntarySwitches),
+            "fds" | "fd_count" => Ok(ProcessSorting::FdCount),
+            "fd%" | "fd_percent" => Ok(ProcessSorting::FdPercent),
+            "deleted" => Ok(ProcessSorting::DeletedCode),
+            "tty" | "tty_nr" => Ok(ProcessSorting::Tty),
+            "sid" | "session" => Ok(ProcessSorting::SessionId),
+            "pgid" | "pgrp" => Ok(ProcessSorting::ProcessGroupId),
+            _ => Err(BottomError::ConfigError(format!(
+                "\"{}\" is an invalid process column.",
+                s
+            ))),
+        }
+    }
+}
+
+/// Columns that are hidden unless enabled with the `process_columns` option, in display order.
+pub const OPTIONAL_PROCESS_COLUMNS: [ProcessSorting; 20] = [
+    ProcessSorting::ReadChars,
+    ProcessSorting::WriteChars,
+    ProcessSorting::ReadSyscalls,
+    ProcessSorting::WriteSyscalls,
+    ProcessSorting::CancelledWrite,
+    ProcessSorting::Affinity,
+    ProcessSorting::LastCpu,
+    ProcessSorting::OomScore,
+    ProcessSorting::OomScoreAdj,
+    ProcessSorting::Unit,
+    ProcessSorting::MinorFaults,
+    ProcessSorting::MajorFaults,
+    ProcessSorting::VoluntarySwitches,
+    ProcessSorting::InvoluntarySwitches,
+    ProcessSorting::FdCount,
+    ProcessSorting::FdPercent,
+    ProcessSorting::DeletedCode,
+    ProcessSorting::Tty,
+    ProcessSorting::SessionId,
+    ProcessSorting::ProcessGroupId,
+];
+
 #[derive(Debug, Clone, Default)]
 pub struct ProcessHarvest {
     pub pid: Pid,
@@ -90,8 +188,184 @@ pub struct ProcessHarvest {
     pub total_write_bytes: u64,
     pub process_state: String,
     pub process_state_char: char,
+    /// Bytes read, including from the page cache and terminals.
+    pub read_chars: u64,
+    /// Bytes written, including to the page cache and terminals.
+    pub write_chars: u64,
+    pub read_syscalls: u64,
+    pub write_syscalls: u64,
+    /// Bytes that were going to be written to storage, but were truncated first.
+    pub cancelled_write_bytes: u64,
+    /// The CPUs the process is allowed to run on, in list format (e.g. `0-3,6`).
+    pub cpus_allowed_list: String,
+    /// The CPU the process last ran on.
+    pub last_cpu: Option<u32>,
+    /// How likely the process is to be chosen by the OOM killer; higher values are killed first.
+    pub oom_score: Option<u32>,
+    /// The adjustment added to the OOM score, from -1000 (never kill) to 1000.
+    pub oom_score_adj: Option<i32>,
+    /// The systemd unit (service, scope, or slice) the process belongs to.
+    pub unit: Option<String>,
+    /// Whether the process is a kernel thread rather than a userspace process.
+    pub is_kernel_thread: bool,
+    /// Page faults that did not need to load a page from disk.
+    pub minor_faults: u64,
+    pub minor_faults_per_sec: f64,
+    /// Page faults that needed to load a page from disk.
+    pub major_faults: u64,
+    pub major_faults_per_sec: f64,
+    /// Context switches where the process gave up the CPU, such as to wait on I/O.
+    pub voluntary_switches: u64,
+    pub voluntary_switches_per_sec: f64,
+    /// Context switches where the process was preempted.
+    pub involuntary_switches: u64,
+    pub involuntary_switches_per_sec: f64,
+    /// The number of open file descriptors.
+    pub fd_count: Option<u64>,
+    /// The soft limit on open file descriptors, or `None` if unlimited or unknown.
+    pub fd_limit: Option<u64>,
+    /// Whether the executable has been deleted or replaced since the process started.
+    pub is_exe_deleted: bool,
+    /// Whether any executable mapping, such as a shared library, has been deleted or replaced.
+    pub has_deleted_libraries: bool,
+    /// The controlling terminal, e.g. `pts/0`, if there is one.
+    pub tty: Option<String>,
+    pub session_id: Option<Pid>,
+    pub process_group_id: Option<Pid>,
 
     /// This is the *effective* user ID.
     #[cfg(target_family = "unix")]
     pub uid: Option<libc::uid_t>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_process_column_from_str() {
+        let columns = [
+            ("rchar", ProcessSorting::ReadChars),
+            ("wchar", ProcessSorting::WriteChars),
+            ("syscr", ProcessSorting::ReadSyscalls),
+            ("syscw", ProcessSorting::WriteSyscalls),
+            ("cancelled_write_bytes", ProcessSorting::CancelledWrite),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_process_column_from_str_aliases() {
+        assert_eq!(
+            ProcessSorting::from_str("RCHAR").unwrap(),
+            ProcessSorting::ReadChars
+        );
+    }
+
+    #[test]
+    fn test_process_column_from_str_invalid() {
+        assert!(ProcessSorting::from_str("").is_err());
+        assert!(ProcessSorting::from_str("cpu").is_err());
+        assert!(ProcessSorting::from_str("rchar ").is_err());
+    }
+
+    #[test]
+    fn test_affinity_and_last_cpu_columns_from_str() {
+        let columns = [
+            ("affinity", ProcessSorting::Affinity),
+            ("cpus_allowed_list", ProcessSorting::Affinity),
+            ("last_cpu", ProcessSorting::LastCpu),
+            ("processor", ProcessSorting::LastCpu),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_oom_columns_from_str() {
+        let columns = [
+            ("oom_score", ProcessSorting::OomScore),
+            ("oom_score_adj", ProcessSorting::OomScoreAdj),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_unit_columns_from_str() {
+        let columns = [
+            ("unit", ProcessSorting::Unit),
+            ("systemd_unit", ProcessSorting::Unit),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_fault_and_switch_columns_from_str() {
+        let columns = [
+            ("minflt", ProcessSorting::MinorFaults),
+            ("majflt", ProcessSorting::MajorFaults),
+            ("vcsw", ProcessSorting::VoluntarySwitches),
+            ("voluntary_ctxt_switches", ProcessSorting::VoluntarySwitches),
+            ("ivcsw", ProcessSorting::InvoluntarySwitches),
+            (
+                "nonvoluntary_ctxt_switches",
+                ProcessSorting::InvoluntarySwitches,
+            ),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_fd_columns_from_str() {
+        let columns = [
+            ("fds", ProcessSorting::FdCount),
+            ("fd_count", ProcessSorting::FdCount),
+            ("fd%", ProcessSorting::FdPercent),
+            ("fd_percent", ProcessSorting::FdPercent),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_deleted_columns_from_str() {
+        let columns = [("deleted", ProcessSorting::DeletedCode)];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+
+    #[test]
+    fn test_tty_session_and_group_columns_from_str() {
+        let columns = [
+            ("tty", ProcessSorting::Tty),
+            ("tty_nr", ProcessSorting::Tty),
+            ("sid", ProcessSorting::SessionId),
+            ("session", ProcessSorting::SessionId),
+            ("pgid", ProcessSorting::ProcessGroupId),
+            ("pgrp", ProcessSorting::ProcessGroupId),
+        ];
+
+        for (name, column) in &columns {
+            assert_eq!(&ProcessSorting::from_str(name).unwrap(), column);
+        }
+    }
+}
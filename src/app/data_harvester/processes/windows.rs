@@ -73,6 +73,7 @@ pub fn get_process_data(
             total_write_bytes: disk_usage.total_written_bytes,
             process_state: process_val.status().to_string(),
             process_state_char: 'R',
+            ..Default::default()
         });
     }
 
@@ -0,0 +1,191 @@
+//! Gets network protocol statistics from `/proc/net/snmp` and `/proc/net/netstat`, and socket
+//! counts from `/proc/net/sockstat` and `/proc/net/sockstat6`.
+
+use std::collections::HashMap;
+
+use super::{ProtocolHarvest, ProtocolStat, SocketHarvest, SocketStat, TCP_RETRANSMITS};
+
+/// The statistics to harvest, as the section and field in the `/proc` files, and a display name.
+const PROTOCOL_STATS: [(&str, &str, &str); 16] = [
+    ("Tcp", "ActiveOpens", "TCP active opens"),
+    ("Tcp", "PassiveOpens", "TCP passive opens"),
+    ("Tcp", "AttemptFails", "TCP failed connection attempts"),
+    ("Tcp", "EstabResets", "TCP established resets"),
+    ("Tcp", "OutRsts", "TCP resets sent"),
+    ("Tcp", "RetransSegs", TCP_RETRANSMITS),
+    ("Tcp", "InErrs", "TCP receive errors"),
+    ("TcpExt", "TCPTimeouts", "TCP timeouts"),
+    ("TcpExt", "ListenOverflows", "TCP listen queue overflows"),
+    ("TcpExt", "ListenDrops", "TCP listen drops"),
+    ("Udp", "InDatagrams", "UDP datagrams received"),
+    ("Udp", "OutDatagrams", "UDP datagrams sent"),
+    ("Udp", "NoPorts", "UDP datagrams to unknown ports"),
+    ("Udp", "InErrors", "UDP receive errors"),
+    ("Udp", "RcvbufErrors", "UDP receive buffer overflows"),
+    ("Udp", "SndbufErrors", "UDP send buffer overflows"),
+];
+
+/// The socket counters to harvest, as the section and field in the `sockstat` files, a display
+/// name, and whether the value is memory in pages.
+const SOCKET_STATS: [(&str, &str, &str, bool); 14] = [
+    ("sockets", "used", "Sockets used", false),
+    ("TCP", "inuse", "TCP in use", false),
+    ("TCP", "orphan", "TCP orphaned", false),
+    ("TCP", "tw", "TCP time-wait", false),
+    ("TCP", "alloc", "TCP allocated", false),
+    ("TCP", "mem", "TCP memory", true),
+    ("TCP6", "inuse", "TCP6 in use", false),
+    ("UDP", "inuse", "UDP in use", false),
+    ("UDP", "mem", "UDP memory", true),
+    ("UDP6", "inuse", "UDP6 in use", false),
+    ("RAW", "inuse", "RAW in use", false),
+    ("RAW6", "inuse", "RAW6 in use", false),
+    ("FRAG", "inuse", "FRAG in use", false),
+    ("FRAG6", "inuse", "FRAG6 in use", false),
+];
+
+pub fn get_protocol_data(actually_get: bool) -> Option<ProtocolHarvest> {
+    if !actually_get {
+        return None;
+    }
+
+    let mut values: HashMap<String, u64> = HashMap::new();
+    for path in ["/proc/net/snmp", "/proc/net/netstat"] {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            parse_snmp_file(&contents, &mut values);
+        }
+    }
+
+    Some(
+        PROTOCOL_STATS
+            .iter()
+            .filter_map(|(section, field, name)| {
+                values
+                    .get(&format!("{}.{}", section, field))
+                    .map(|total| ProtocolStat {
+                        name,
+                        total: *total,
+                    })
+            })
+            .collect(),
+    )
+}
+
+pub fn get_socket_data(actually_get: bool) -> Option<SocketHarvest> {
+    if !actually_get {
+        return None;
+    }
+
+    let mut values: HashMap<String, u64> = HashMap::new();
+    for path in ["/proc/net/sockstat", "/proc/net/sockstat6"] {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            parse_sockstat_file(&contents, &mut values);
+        }
+    }
+    let page_size = procfs::page_size().unwrap_or(4096) as u64;
+
+    Some(
+        SOCKET_STATS
+            .iter()
+            .map(|(section, field, name, is_pages)| SocketStat {
+                name,
+                value: values.get(&format!("{}.{}", section, field)).map(|value| {
+                    if *is_pages {
+                        value * page_size
+                    } else {
+                        *value
+                    }
+                }),
+                is_bytes: *is_pages,
+            })
+            .collect(),
+    )
+}
+
+/// Parses a file in the format of `/proc/net/sockstat`, where each line is a section name
+/// followed by pairs of field names and values:
+///
+/// ```text
+/// TCP: inuse 5 orphan 0 tw 2 alloc 7 mem 1
+/// ```
+///
+/// Values are stored as `Section.Field`.
+fn parse_sockstat_file(contents: &str, values: &mut HashMap<String, u64>) {
+    for line in contents.lines() {
+        if let Some((section, data)) = line.split_once(':') {
+            let mut data = data.split_whitespace();
+            while let (Some(field), Some(value)) = (data.next(), data.next()) {
+                if let Ok(value) = value.parse::<u64>() {
+                    values.insert(format!("{}.{}", section, field), value);
+                }
+            }
+        }
+    }
+}
+
+/// Parses a file in the format of `/proc/net/snmp`, where each section is a line of field names
+/// followed by a line of values, both prefixed with the section name:
+///
+/// ```text
+/// Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens ...
+/// Tcp: 1 200 120000 -1 4081 ...
+/// ```
+///
+/// Values are stored as `Section.Field`.  Negative values, which are only used for limits, are
+/// skipped.
+fn parse_snmp_file(contents: &str, values: &mut HashMap<String, u64>) {
+    let mut lines = contents.lines();
+    while let (Some(header), Some(data)) = (lines.next(), lines.next()) {
+        if let (Some((section, fields)), Some((data_section, data))) =
+            (header.split_once(':'), data.split_once(':'))
+        {
+            if section != data_section {
+                continue;
+            }
+
+            for (field, value) in fields.split_whitespace().zip(data.split_whitespace()) {
+                if let Ok(value) = value.parse::<u64>() {
+                    values.insert(format!("{}.{}", section, field), value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snmp_parse() {
+        let mut values = HashMap::new();
+        parse_snmp_file(
+            "Ip: Forwarding DefaultTTL\n\
+            Ip: 1 64\n\
+            Tcp: RtoAlgorithm MaxConn ActiveOpens RetransSegs\n\
+            Tcp: 1 -1 4081 12\n",
+            &mut values,
+        );
+
+        assert_eq!(values.get("Ip.DefaultTTL"), Some(&64));
+        assert_eq!(values.get("Tcp.ActiveOpens"), Some(&4081));
+        assert_eq!(values.get("Tcp.RetransSegs"), Some(&12));
+        assert_eq!(values.get("Tcp.MaxConn"), None);
+    }
+
+    #[test]
+    fn test_sockstat_parse() {
+        let mut values = HashMap::new();
+        parse_sockstat_file(
+            "sockets: used 290\n\
+            TCP: inuse 5 orphan 0 tw 2 alloc 7 mem 1\n\
+            FRAG: inuse 0 memory 0\n",
+            &mut values,
+        );
+
+        assert_eq!(values.get("sockets.used"), Some(&290));
+        assert_eq!(values.get("TCP.tw"), Some(&2));
+        assert_eq!(values.get("TCP.mem"), Some(&1));
+        assert_eq!(values.get("FRAG.memory"), Some(&0));
+    }
+}
@@ -0,0 +1,35 @@
+//! Data collection for network protocol statistics, such as TCP retransmits, and socket counts.
+//!
+//! Only Linux is supported, through `/proc/net/snmp`, `/proc/net/netstat`, and
+//! `/proc/net/sockstat`.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        pub mod linux;
+        pub use self::linux::*;
+    }
+}
+
+/// The name of the statistic counting retransmitted TCP segments.
+pub const TCP_RETRANSMITS: &str = "TCP retransmits";
+
+#[derive(Clone, Debug)]
+pub struct ProtocolStat {
+    pub name: &'static str,
+    /// The total count since boot.
+    pub total: u64,
+}
+
+pub type ProtocolHarvest = Vec<ProtocolStat>;
+
+#[derive(Clone, Debug)]
+pub struct SocketStat {
+    pub name: &'static str,
+    /// The current value, or `None` if the kernel did not report it.
+    pub value: Option<u64>,
+    /// Whether the value is an amount of memory in bytes, rather than a count.
+    pub is_bytes: bool,
+}
+
+/// One entry for each socket statistic harvested, always in the same order.
+pub type SocketHarvest = Vec<SocketStat>;
@@ -0,0 +1,114 @@
+//! Gets general system information on Linux from `/proc` and `utmp`.
+
+use super::{SystemHarvest, TaskCounts};
+use crate::app::data_harvester::processes::ProcessHarvest;
+
+/// The `ut_type` of a `utmp` entry for a normal user login.
+const USER_PROCESS: libc::c_short = 7;
+
+const UTMP_PATH: &str = "/var/run/utmp";
+
+/// Task counts are derived from `processes` if they were already harvested this tick, and
+/// otherwise read from `/proc` directly.
+pub fn get_system_data(
+    actually_get: bool, processes: Option<&[ProcessHarvest]>,
+) -> Option<SystemHarvest> {
+    if !actually_get {
+        return None;
+    }
+
+    Some(SystemHarvest {
+        hostname: read_trimmed("/proc/sys/kernel/hostname"),
+        kernel_version: read_trimmed("/proc/sys/kernel/osrelease"),
+        uptime_secs: get_uptime_secs().unwrap_or(0),
+        user_count: get_user_count(),
+        tasks: match processes {
+            Some(processes) => get_task_counts_from_processes(processes),
+            None => get_task_counts(),
+        },
+        file_descriptors: get_file_descriptors(),
+    })
+}
+
+fn read_trimmed(path: &str) -> String {
+    std::fs::read_to_string(path)
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default()
+}
+
+fn get_uptime_secs() -> Option<u64> {
+    let uptime = std::fs::read_to_string("/proc/uptime").ok()?;
+    let secs = uptime.split_whitespace().next()?.parse::<f64>().ok()?;
+
+    Some(secs as u64)
+}
+
+/// Counts the logged in user sessions by walking the raw `utmp` records. This avoids `getutxent`,
+/// which is not available on every libc.
+fn get_user_count() -> usize {
+    if let Ok(utmp) = std::fs::read(UTMP_PATH) {
+        utmp.chunks_exact(std::mem::size_of::<libc::utmpx>())
+            .filter(|record| {
+                // `ut_type` is the first field of the record.
+                libc::c_short::from_ne_bytes([record[0], record[1]]) == USER_PROCESS
+            })
+            .count()
+    } else {
+        0
+    }
+}
+
+/// Reads the system-wide file descriptor usage from `/proc/sys/fs/file-nr`, which holds the
+/// number of allocated handles, the number of allocated but unused handles, and the maximum.
+fn get_file_descriptors() -> Option<(u64, u64)> {
+    let file_nr = std::fs::read_to_string("/proc/sys/fs/file-nr").ok()?;
+    let mut fields = file_nr
+        .split_whitespace()
+        .map(|field| field.parse::<u64>().ok());
+    let allocated = fields.next()??;
+    let unused = fields.next()??;
+    let max = fields.next()??;
+
+    Some((allocated.saturating_sub(unused), max))
+}
+
+fn get_task_counts_from_processes(processes: &[ProcessHarvest]) -> TaskCounts {
+    let mut tasks = TaskCounts::default();
+    for process in processes {
+        tasks.add_state(process.process_state_char);
+    }
+
+    tasks
+}
+
+fn get_task_counts() -> TaskCounts {
+    let mut tasks = TaskCounts::default();
+
+    if let Ok(entries) = std::fs::read_dir("/proc") {
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let is_pid = file_name
+                .to_str()
+                .map(|name| name.bytes().all(|b| b.is_ascii_digit()))
+                .unwrap_or(false);
+            if !is_pid {
+                continue;
+            }
+
+            if let Ok(stat) = std::fs::read_to_string(entry.path().join("stat")) {
+                // The state is the first field after the command name, which is wrapped in
+                // parentheses and may itself contain spaces or parentheses.
+                let state = stat
+                    .rfind(')')
+                    .and_then(|pos| stat[pos + 1..].split_whitespace().next())
+                    .and_then(|state| state.chars().next());
+
+                if let Some(state) = state {
+                    tasks.add_state(state);
+                }
+            }
+        }
+    }
+
+    tasks
+}
@@ -0,0 +1,50 @@
+//! Data collection for general system information, such as the hostname, kernel version, uptime,
+//! logged in users, task counts, and file descriptor usage.
+//!
+//! For Linux, this is read from `/proc` and `utmp`.
+//! For macOS and Windows, this is handled by sysinfo.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        pub mod linux;
+        pub use self::linux::*;
+    } else if #[cfg(any(target_os = "macos", target_os = "windows"))] {
+        pub mod sysinfo;
+        pub use self::sysinfo::*;
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct TaskCounts {
+    pub total: u64,
+    pub running: u64,
+    pub sleeping: u64,
+    pub stopped: u64,
+    pub zombie: u64,
+}
+
+impl TaskCounts {
+    /// Counts a task given its single character state, as found in `/proc/<pid>/stat`.
+    #[cfg(target_os = "linux")]
+    pub fn add_state(&mut self, state: char) {
+        self.total += 1;
+        match state {
+            'R' => self.running += 1,
+            'S' | 'D' | 'I' => self.sleeping += 1,
+            'T' | 't' => self.stopped += 1,
+            'Z' => self.zombie += 1,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct SystemHarvest {
+    pub hostname: String,
+    pub kernel_version: String,
+    pub uptime_secs: u64,
+    pub user_count: usize,
+    pub tasks: TaskCounts,
+    /// The number of file descriptors in use and the system-wide limit, if known.
+    pub file_descriptors: Option<(u64, u64)>,
+}
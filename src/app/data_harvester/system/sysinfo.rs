@@ -0,0 +1,32 @@
+//! Gets general system information via sysinfo.
+
+use super::{SystemHarvest, TaskCounts};
+
+pub fn get_system_data(sys: &sysinfo::System, actually_get: bool) -> Option<SystemHarvest> {
+    use sysinfo::{ProcessExt, ProcessStatus, SystemExt};
+
+    if !actually_get {
+        return None;
+    }
+
+    let mut tasks = TaskCounts::default();
+    for process in sys.processes().values() {
+        tasks.total += 1;
+        match process.status() {
+            ProcessStatus::Run => tasks.running += 1,
+            ProcessStatus::Sleep | ProcessStatus::Idle => tasks.sleeping += 1,
+            ProcessStatus::Stop | ProcessStatus::Tracing => tasks.stopped += 1,
+            ProcessStatus::Zombie => tasks.zombie += 1,
+            _ => {}
+        }
+    }
+
+    Some(SystemHarvest {
+        hostname: sys.host_name().unwrap_or_default(),
+        kernel_version: sys.kernel_version().unwrap_or_default(),
+        uptime_secs: sys.uptime(),
+        user_count: sys.users().len(),
+        tasks,
+        file_descriptors: None,
+    })
+}
@@ -898,17 +898,25 @@ pub enum BottomWidgetType {
     BasicNet,
     BasicTables,
     Battery,
+    LoadAvg,
+    Summary,
+    NetInterfaces,
+    Protocols,
+    Sockets,
 }
 
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | Proc | ProcSort | Temp | CpuLegend | NetInterfaces | Protocols | Sockets
+        )
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(self, Cpu | Net | Mem | LoadAvg)
     }
 
     pub fn get_pretty_name(&self) -> &str {
@@ -921,6 +929,11 @@ impl BottomWidgetType {
             Temp => "Temperature",
             Disk => "Disks",
             Battery => "Battery",
+            LoadAvg => "Load Average",
+            Summary => "Summary",
+            NetInterfaces => "Interfaces",
+            Protocols => "Protocols",
+            Sockets => "Sockets",
             _ => "",
         }
     }
@@ -944,6 +957,11 @@ impl std::str::FromStr for BottomWidgetType {
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
             "disk" => Ok(BottomWidgetType::Disk),
+            "load" | "load_avg" => Ok(BottomWidgetType::LoadAvg),
+            "summary" | "header" => Ok(BottomWidgetType::Summary),
+            "iface" | "interfaces" => Ok(BottomWidgetType::NetInterfaces),
+            "proto" | "protocols" => Ok(BottomWidgetType::Protocols),
+            "sock" | "sockets" => Ok(BottomWidgetType::Sockets),
             "empty" => Ok(BottomWidgetType::Empty),
             "battery" | "batt" if cfg!(feature = "battery") => Ok(BottomWidgetType::Battery),
             _ => {
@@ -965,6 +983,16 @@ Supported widget names:
 +--------------------------+
 |           disk           |
 +--------------------------+
+|      load, load_avg      |
++--------------------------+
+|      summary, header     |
++--------------------------+
+|    iface, interfaces     |
++--------------------------+
+|     proto, protocols     |
++--------------------------+
+|       sock, sockets      |
++--------------------------+
 |       batt, battery      |
 +--------------------------+
                 ",
@@ -987,6 +1015,16 @@ Supported widget names:
 |     temp, temperature    |
 +--------------------------+
 |           disk           |
++--------------------------+
+|      load, load_avg      |
++--------------------------+
+|      summary, header     |
++--------------------------+
+|    iface, interfaces     |
++--------------------------+
+|     proto, protocols     |
++--------------------------+
+|       sock, sockets      |
 +--------------------------+
                 ",
                         s
@@ -1006,4 +1044,9 @@ pub struct UsedWidgets {
     pub use_disk: bool,
     pub use_temp: bool,
     pub use_battery: bool,
+    pub use_load_avg: bool,
+    pub use_summary: bool,
+    pub use_net_interfaces: bool,
+    pub use_protocols: bool,
+    pub use_sockets: bool,
 }
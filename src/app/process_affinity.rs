@@ -0,0 +1,172 @@
+//! This file is meant to house (OS specific) implementations on how to get and set the CPU affinity
+//! of processes.
+
+use crate::utils::error::{BottomError, Result};
+use crate::Pid;
+
+/// Returns the number of CPUs a process can be pinned to, for Linux.
+#[cfg(target_os = "linux")]
+fn get_affinity_cpu_count() -> usize {
+    let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) };
+    if count > 0 {
+        std::cmp::min(count as usize, libc::CPU_SETSIZE as usize)
+    } else {
+        1
+    }
+}
+
+/// Returns which CPUs a process is allowed to run on, given a PID, for Linux.
+#[cfg(target_os = "linux")]
+pub fn get_process_affinity(pid: Pid) -> Result<Vec<bool>> {
+    let mut cpu_set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    let output = unsafe {
+        libc::sched_getaffinity(
+            pid as libc::pid_t,
+            std::mem::size_of::<libc::cpu_set_t>(),
+            &mut cpu_set,
+        )
+    };
+    if output != 0 {
+        return Err(BottomError::GenericError(get_affinity_error_string(
+            std::io::Error::last_os_error().raw_os_error(),
+        )));
+    }
+
+    Ok(get_selected_cpus(&cpu_set, get_affinity_cpu_count()))
+}
+
+/// Converts a CPU set into whether each of the first `cpu_count` CPUs is selected.
+#[cfg(target_os = "linux")]
+fn get_selected_cpus(cpu_set: &libc::cpu_set_t, cpu_count: usize) -> Vec<bool> {
+    (0..cpu_count)
+        .map(|cpu| unsafe { libc::CPU_ISSET(cpu, cpu_set) })
+        .collect()
+}
+
+/// Converts whether each CPU is selected into a CPU set.
+#[cfg(target_os = "linux")]
+fn get_cpu_set(selected_cpus: &[bool]) -> libc::cpu_set_t {
+    let mut cpu_set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for (cpu, _selected) in selected_cpus
+        .iter()
+        .enumerate()
+        .filter(|(_cpu, selected)| **selected)
+    {
+        unsafe { libc::CPU_SET(cpu, &mut cpu_set) };
+    }
+
+    cpu_set
+}
+
+/// Restricts a process and all of its threads to the selected CPUs, given a PID, for Linux.
+/// This matches `taskset -a`, as `sched_setaffinity` on its own only affects a single thread.
+#[cfg(target_os = "linux")]
+pub fn set_process_affinity(pid: Pid, selected_cpus: &[bool]) -> Result<()> {
+    let cpu_set = get_cpu_set(selected_cpus);
+
+    let mut tids = std::fs::read_dir(format!("/proc/{}/task", pid))
+        .map(|tasks| {
+            tasks
+                .flatten()
+                .filter_map(|task| task.file_name().to_str()?.parse::<Pid>().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if tids.is_empty() {
+        // Either the process is gone or we can't list its threads, so just try the PID itself.
+        tids.push(pid);
+    }
+
+    let mut failed_tids = Vec::new();
+    let mut first_err_code = None;
+    for tid in tids {
+        let output = unsafe {
+            libc::sched_setaffinity(
+                tid as libc::pid_t,
+                std::mem::size_of::<libc::cpu_set_t>(),
+                &cpu_set,
+            )
+        };
+        if output != 0 {
+            let err_code = std::io::Error::last_os_error().raw_os_error();
+
+            // Threads may exit while we're going through them, which is fine.
+            if tid != pid && err_code == Some(libc::ESRCH) {
+                continue;
+            }
+
+            failed_tids.push(tid.to_string());
+            first_err_code = first_err_code.or(err_code);
+        }
+    }
+
+    if failed_tids.is_empty() {
+        Ok(())
+    } else {
+        Err(BottomError::GenericError(format!(
+            "Failed to set the affinity of thread(s) {}. {}",
+            failed_tids.join(", "),
+            get_affinity_error_string(first_err_code)
+        )))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_affinity_error_string(err_code: Option<i32>) -> String {
+    let err = match err_code {
+        Some(libc::ESRCH) => "the target process did not exist.",
+        Some(libc::EPERM) => "the calling process does not have the permissions to change the affinity of the target process(es).",
+        Some(libc::EINVAL) => "none of the selected CPUs are available to the target process(es).",
+        _ => "Unknown error occurred.",
+    };
+
+    if let Some(err_code) = err_code {
+        format!("Error code {} - {}", err_code, err)
+    } else {
+        format!("Error code ??? - {}", err)
+    }
+}
+
+/// Getting CPU affinity is currently only supported on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn get_process_affinity(_pid: Pid) -> Result<Vec<bool>> {
+    Err(BottomError::GenericError(
+        "Getting CPU affinity is not supported on this platform.".to_string(),
+    ))
+}
+
+/// Setting CPU affinity is currently only supported on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn set_process_affinity(_pid: Pid, _selected_cpus: &[bool]) -> Result<()> {
+    Err(BottomError::GenericError(
+        "Setting CPU affinity is not supported on this platform.".to_string(),
+    ))
+}
+
+#[cfg(test)]
+#[cfg(target_os = "linux")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cpu_set_round_trip() {
+        let selected_cpus = vec![true, false, false, true, true, false, false, false];
+        let cpu_set = get_cpu_set(&selected_cpus);
+        assert_eq!(
+            get_selected_cpus(&cpu_set, selected_cpus.len()),
+            selected_cpus
+        );
+
+        // CPUs past the end of the selection are never set.
+        assert_eq!(
+            get_selected_cpus(&cpu_set, 10)[selected_cpus.len()..],
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn test_cpu_set_round_trip_empty() {
+        let cpu_set = get_cpu_set(&[]);
+        assert_eq!(get_selected_cpus(&cpu_set, 4), vec![false; 4]);
+    }
+}
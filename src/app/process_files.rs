@@ -0,0 +1,156 @@
+//! This file is meant to house (OS specific) implementations on how to list the files a process
+//! has open.
+
+use crate::utils::error::{BottomError, Result};
+use crate::Pid;
+
+#[derive(Clone, Debug, Default)]
+pub struct OpenFile {
+    pub fd: u32,
+    /// What kind of file the descriptor refers to, such as a file, socket, or pipe.
+    pub file_type: &'static str,
+    /// Where the descriptor points to, e.g. a path or `socket:[12345]`.
+    pub target: String,
+    /// The file offset, if known.
+    pub position: Option<u64>,
+    /// The access mode and notable status flags the file was opened with, e.g. `rw,cloexec`.
+    pub flags: Option<String>,
+}
+
+/// Lists the files a process has open, given a PID, for Linux.
+#[cfg(target_os = "linux")]
+pub fn get_open_files(pid: Pid) -> Result<Vec<OpenFile>> {
+    let fd_dir =
+        std::fs::read_dir(format!("/proc/{}/fd", pid)).map_err(|err| get_files_error(&err))?;
+
+    let mut open_files = fd_dir
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let fd = entry.file_name().to_str()?.parse::<u32>().ok()?;
+
+            // The descriptor may have been closed since we listed the directory, so just skip it.
+            let target = std::fs::read_link(entry.path())
+                .ok()?
+                .to_string_lossy()
+                .to_string();
+            let (position, flags) = get_fd_info(pid, fd);
+
+            Some(OpenFile {
+                fd,
+                file_type: get_file_type(&target),
+                target,
+                position,
+                flags,
+            })
+        })
+        .collect::<Vec<_>>();
+    open_files.sort_by_key(|open_file| open_file.fd);
+
+    Ok(open_files)
+}
+
+#[cfg(target_os = "linux")]
+fn get_file_type(target: &str) -> &'static str {
+    if target.starts_with("socket:") {
+        "socket"
+    } else if target.starts_with("pipe:") {
+        "pipe"
+    } else if target.starts_with("anon_inode:") {
+        "anon inode"
+    } else if target.starts_with("/dev/") {
+        "device"
+    } else if target.starts_with('/') {
+        "file"
+    } else {
+        "other"
+    }
+}
+
+/// Reads the position and flags of a file descriptor from `/proc/<pid>/fdinfo/<fd>`.
+#[cfg(target_os = "linux")]
+fn get_fd_info(pid: Pid, fd: u32) -> (Option<u64>, Option<String>) {
+    let mut position = None;
+    let mut flags = None;
+
+    if let Ok(fd_info) = std::fs::read_to_string(format!("/proc/{}/fdinfo/{}", pid, fd)) {
+        for line in fd_info.lines() {
+            if let Some((key, value)) = line.split_once(':') {
+                match key {
+                    "pos" => position = value.trim().parse::<u64>().ok(),
+                    "flags" => {
+                        // Flags are given in octal.
+                        flags = i32::from_str_radix(value.trim(), 8)
+                            .ok()
+                            .map(get_flags_string)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    (position, flags)
+}
+
+#[cfg(target_os = "linux")]
+fn get_flags_string(flags: i32) -> String {
+    const STATUS_FLAGS: [(i32, &str); 4] = [
+        (libc::O_APPEND, "append"),
+        (libc::O_NONBLOCK, "nonblock"),
+        (libc::O_DIRECT, "direct"),
+        (libc::O_CLOEXEC, "cloexec"),
+    ];
+
+    let access_mode = match flags & libc::O_ACCMODE {
+        libc::O_RDONLY => "r",
+        libc::O_WRONLY => "w",
+        _ => "rw",
+    };
+
+    std::iter::once(access_mode)
+        .chain(
+            STATUS_FLAGS
+                .iter()
+                .filter(|(flag, _name)| flags & flag != 0)
+                .map(|(_flag, name)| *name),
+        )
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(target_os = "linux")]
+fn get_files_error(err: &std::io::Error) -> BottomError {
+    let reason = match err.kind() {
+        std::io::ErrorKind::NotFound => "the target process did not exist.",
+        std::io::ErrorKind::PermissionDenied => "the calling process does not have the permissions to read the open files of the target process.",
+        _ => "Unknown error occurred.",
+    };
+
+    if let Some(err_code) = err.raw_os_error() {
+        BottomError::GenericError(format!("Error code {} - {}", err_code, reason))
+    } else {
+        BottomError::GenericError(format!("Error code ??? - {}", reason))
+    }
+}
+
+/// Listing open files is currently only supported on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn get_open_files(_pid: Pid) -> Result<Vec<OpenFile>> {
+    Err(BottomError::GenericError(
+        "Listing open files is not supported on this platform.".to_string(),
+    ))
+}
+
+#[cfg(test)]
+#[cfg(target_os = "linux")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_flags_string() {
+        assert_eq!(get_flags_string(0o0), "r");
+        assert_eq!(get_flags_string(0o2), "rw");
+        assert_eq!(get_flags_string(0o2000001), "w,cloexec");
+        assert_eq!(get_flags_string(0o4002), "rw,nonblock");
+    }
+}
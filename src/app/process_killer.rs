@@ -13,6 +13,8 @@ use winapi::{
 use crate::utils::error::BottomError;
 use crate::Pid;
 
+use std::collections::{HashMap, HashSet};
+
 #[cfg(target_os = "windows")]
 struct Process(HANDLE);
 
@@ -36,7 +38,8 @@ impl Process {
     }
 }
 
-/// Kills a process, given a PID, for unix.
+/// Kills a process, given a PID, for unix.  A negative PID instead signals every process in the
+/// process group with that ID.
 #[cfg(target_family = "unix")]
 pub fn kill_process_given_pid(pid: Pid, signal: usize) -> crate::utils::error::Result<()> {
     let output = unsafe { libc::kill(pid as i32, signal as i32) };
@@ -76,3 +79,129 @@ pub fn kill_process_given_pid(pid: Pid) -> crate::utils::error::Result<()> {
 
     Ok(())
 }
+
+/// Returns the root processes and all of their descendants, given the PID and parent PID of every
+/// process.  Children are ordered before their parents, and each process is listed at most once,
+/// even if a root is repeated or the parent links form a cycle.
+///
+/// Neither `protected_pid` nor any of its ancestors are listed, though their other descendants
+/// still are.
+pub fn get_process_subtree(
+    root_pids: &[Pid], processes: impl Iterator<Item = (Pid, Option<Pid>)>, protected_pid: Pid,
+) -> Vec<Pid> {
+    let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    let mut parents: HashMap<Pid, Pid> = HashMap::new();
+    for (pid, parent_pid) in processes {
+        if let Some(parent_pid) = parent_pid {
+            children.entry(parent_pid).or_default().push(pid);
+            parents.insert(pid, parent_pid);
+        }
+    }
+
+    // Walk up from the protected process; a repeated PID means the parent links form a cycle.
+    let mut protected_pids = HashSet::new();
+    let mut current_pid = Some(protected_pid);
+    while let Some(pid) = current_pid {
+        if !protected_pids.insert(pid) {
+            break;
+        }
+        current_pid = parents.get(&pid).copied();
+    }
+
+    // An iterative post-order traversal; a process is only added once all of its children have
+    // been.
+    let mut visited = HashSet::new();
+    let mut subtree = vec![];
+    let mut stack = root_pids
+        .iter()
+        .rev()
+        .map(|pid| (*pid, false))
+        .collect::<Vec<_>>();
+    while let Some((pid, are_children_added)) = stack.pop() {
+        if are_children_added {
+            if !protected_pids.contains(&pid) {
+                subtree.push(pid);
+            }
+        } else if visited.insert(pid) {
+            stack.push((pid, true));
+            if let Some(child_pids) = children.get(&pid) {
+                stack.extend(child_pids.iter().rev().map(|child_pid| (*child_pid, false)));
+            }
+        }
+    }
+
+    subtree
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_process_subtree_order() {
+        // 1 -> 2 -> (3, 4), and 5 is unrelated.
+        let processes = vec![
+            (1, None),
+            (2, Some(1)),
+            (3, Some(2)),
+            (4, Some(2)),
+            (5, Some(1)),
+        ];
+
+        assert_eq!(
+            get_process_subtree(&[2], processes.clone().into_iter(), 0),
+            vec![3, 4, 2]
+        );
+        assert_eq!(
+            get_process_subtree(&[1], processes.into_iter(), 0),
+            vec![3, 4, 2, 5, 1]
+        );
+    }
+
+    #[test]
+    fn test_process_subtree_cycles_and_duplicates() {
+        // 1 and 2 are each other's parents, which should never happen but mustn't hang.
+        let processes = vec![(1, Some(2)), (2, Some(1)), (3, Some(2))];
+        assert_eq!(
+            get_process_subtree(&[1], processes.into_iter(), 0),
+            vec![3, 2, 1]
+        );
+
+        // Selecting a process and its descendant lists the descendant once.
+        let processes = vec![(1, None), (2, Some(1)), (3, Some(2))];
+        assert_eq!(
+            get_process_subtree(&[1, 2, 1], processes.into_iter(), 0),
+            vec![3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_process_subtree_excludes_own_pid_and_ancestors() {
+        // 1 -> 2 -> (3, 4 -> 5), and 1 -> 6.
+        let processes = vec![
+            (1, None),
+            (2, Some(1)),
+            (3, Some(2)),
+            (4, Some(2)),
+            (5, Some(4)),
+            (6, Some(1)),
+        ];
+
+        // Protecting 4 also protects 2 and 1, but not their other descendants.
+        assert_eq!(
+            get_process_subtree(&[1], processes.clone().into_iter(), 4),
+            vec![3, 5, 6]
+        );
+        assert_eq!(
+            get_process_subtree(&[2], processes.into_iter(), 4),
+            vec![3, 5]
+        );
+
+        // A cycle above the protected process mustn't hang.
+        let processes = vec![(1, Some(2)), (2, Some(1)), (3, Some(2))];
+        assert_eq!(
+            get_process_subtree(&[3], processes.into_iter(), 3),
+            Vec::<Pid>::new()
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! This file is meant to house (OS specific) implementations on how to get and set how likely processes
+//! are to be chosen by the OOM killer.
+
+use crate::utils::error::{BottomError, Result};
+use crate::Pid;
+
+/// The lowest `oom_score_adj`, which stops the OOM killer from ever choosing a process.
+pub const MIN_OOM_SCORE_ADJ: i32 = -1000;
+
+/// The highest `oom_score_adj`, which makes a process the first choice of the OOM killer.
+pub const MAX_OOM_SCORE_ADJ: i32 = 1000;
+
+/// Keeps an `oom_score_adj` within the range the kernel accepts.
+pub fn clamp_oom_score_adj(oom_score_adj: i32) -> i32 {
+    oom_score_adj.max(MIN_OOM_SCORE_ADJ).min(MAX_OOM_SCORE_ADJ)
+}
+
+/// Returns the `oom_score_adj` of a process, given a PID, for Linux.
+#[cfg(target_os = "linux")]
+pub fn get_oom_score_adj(pid: Pid) -> Result<i32> {
+    let oom_score_adj = std::fs::read_to_string(format!("/proc/{}/oom_score_adj", pid))
+        .map_err(|err| get_oom_error(&err))?;
+
+    oom_score_adj
+        .trim()
+        .parse::<i32>()
+        .map_err(|err| BottomError::GenericError(err.to_string()))
+}
+
+/// Sets the `oom_score_adj` of a process, given a PID, for Linux.  Values outside of the valid range
+/// are clamped rather than rejected.
+#[cfg(target_os = "linux")]
+pub fn set_oom_score_adj(pid: Pid, oom_score_adj: i32) -> Result<()> {
+    std::fs::write(
+        format!("/proc/{}/oom_score_adj", pid),
+        clamp_oom_score_adj(oom_score_adj).to_string(),
+    )
+    .map_err(|err| get_oom_error(&err))
+}
+
+#[cfg(target_os = "linux")]
+fn get_oom_error(err: &std::io::Error) -> BottomError {
+    let reason = match err.kind() {
+        std::io::ErrorKind::NotFound => "the target process did not exist.",
+        std::io::ErrorKind::PermissionDenied => "the calling process does not have the permissions to lower the OOM score adjustment of the target process(es).",
+        _ => "Unknown error occurred.",
+    };
+
+    if let Some(err_code) = err.raw_os_error() {
+        BottomError::GenericError(format!("Error code {} - {}", err_code, reason))
+    } else {
+        BottomError::GenericError(format!("Error code ??? - {}", reason))
+    }
+}
+
+/// Adjusting the OOM score is currently only supported on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn get_oom_score_adj(_pid: Pid) -> Result<i32> {
+    Err(BottomError::GenericError(
+        "Adjusting the OOM score is not supported on this platform.".to_string(),
+    ))
+}
+
+/// Adjusting the OOM score is currently only supported on Linux.
+#[cfg(not(target_os = "linux"))]
+pub fn set_oom_score_adj(_pid: Pid, _oom_score_adj: i32) -> Result<()> {
+    Err(BottomError::GenericError(
+        "Adjusting the OOM score is not supported on this platform.".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clamp_oom_score_adj() {
+        assert_eq!(clamp_oom_score_adj(0), 0);
+        assert_eq!(clamp_oom_score_adj(-500), -500);
+        assert_eq!(clamp_oom_score_adj(MIN_OOM_SCORE_ADJ), MIN_OOM_SCORE_ADJ);
+        assert_eq!(clamp_oom_score_adj(MAX_OOM_SCORE_ADJ), MAX_OOM_SCORE_ADJ);
+        assert_eq!(clamp_oom_score_adj(-1001), MIN_OOM_SCORE_ADJ);
+        assert_eq!(clamp_oom_score_adj(1100), MAX_OOM_SCORE_ADJ);
+        assert_eq!(clamp_oom_score_adj(i32::MIN), MIN_OOM_SCORE_ADJ);
+        assert_eq!(clamp_oom_score_adj(i32::MAX), MAX_OOM_SCORE_ADJ);
+    }
+}
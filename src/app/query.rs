@@ -257,7 +257,13 @@ impl ProcessQuery for ProcWidgetState {
                                     compare_prefix: None,
                                 })
                             }
-                            PrefixType::Pid | PrefixType::State | PrefixType::User => {
+                            PrefixType::Pid
+                            | PrefixType::State
+                            | PrefixType::User
+                            | PrefixType::Unit
+                            | PrefixType::KernelThread
+                            | PrefixType::DeletedCode
+                            | PrefixType::Tty => {
                                 // We have to check if someone put an "="...
                                 if content == "=" {
                                     // Check next string if possible
@@ -583,6 +589,18 @@ pub enum PrefixType {
     Name,
     State,
     User,
+    OomScore,
+    OomScoreAdj,
+    Unit,
+    KernelThread,
+    MinorFaults,
+    MajorFaults,
+    VoluntarySwitches,
+    InvoluntarySwitches,
+    DeletedCode,
+    Tty,
+    SessionId,
+    ProcessGroupId,
     __Nonexhaustive,
 }
 
@@ -606,6 +624,18 @@ impl std::str::FromStr for PrefixType {
             "pid" => Ok(Pid),
             "state" => Ok(State),
             "user" => Ok(User),
+            "oom" => Ok(OomScore),
+            "oomadj" => Ok(OomScoreAdj),
+            "unit" => Ok(Unit),
+            "kthread" => Ok(KernelThread),
+            "minflt" => Ok(MinorFaults),
+            "majflt" => Ok(MajorFaults),
+            "vcsw" => Ok(VoluntarySwitches),
+            "ivcsw" => Ok(InvoluntarySwitches),
+            "deleted" => Ok(DeletedCode),
+            "tty" => Ok(Tty),
+            "sid" => Ok(SessionId),
+            "pgid" => Ok(ProcessGroupId),
             _ => Ok(Name),
         }
     }
@@ -632,7 +662,14 @@ impl Prefix {
         } else if let Some((prefix_type, StringQuery::Value(regex_string))) = &mut self.regex_prefix
         {
             match prefix_type {
-                PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::User => {
+                PrefixType::Pid
+                | PrefixType::Name
+                | PrefixType::State
+                | PrefixType::User
+                | PrefixType::Unit
+                | PrefixType::KernelThread
+                | PrefixType::DeletedCode
+                | PrefixType::Tty => {
                     let escaped_regex: String;
                     let final_regex_string = &format!(
                         "{}{}{}{}",
@@ -692,6 +729,32 @@ impl Prefix {
                             false
                         }
                     }
+                    PrefixType::Unit => {
+                        if let Some(unit) = &process.unit {
+                            r.is_match(unit.as_str())
+                        } else {
+                            false
+                        }
+                    }
+                    PrefixType::KernelThread => r.is_match(if process.is_kernel_thread {
+                        "true"
+                    } else {
+                        "false"
+                    }),
+                    PrefixType::DeletedCode => {
+                        r.is_match(if process.is_exe_deleted || process.has_deleted_libraries {
+                            "true"
+                        } else {
+                            "false"
+                        })
+                    }
+                    PrefixType::Tty => {
+                        if let Some(tty) = &process.tty {
+                            r.is_match(tty.as_str())
+                        } else {
+                            false
+                        }
+                    }
                     _ => true,
                 }
             } else {
@@ -734,6 +797,56 @@ impl Prefix {
                     process.tw_f64,
                     numerical_query.value,
                 ),
+                PrefixType::OomScore => process.oom_score.map_or(false, |oom_score| {
+                    matches_condition(
+                        &numerical_query.condition,
+                        oom_score as f64,
+                        numerical_query.value,
+                    )
+                }),
+                PrefixType::OomScoreAdj => process.oom_score_adj.map_or(false, |oom_score_adj| {
+                    matches_condition(
+                        &numerical_query.condition,
+                        oom_score_adj as f64,
+                        numerical_query.value,
+                    )
+                }),
+                PrefixType::SessionId => process.session_id.map_or(false, |session_id| {
+                    matches_condition(
+                        &numerical_query.condition,
+                        session_id as f64,
+                        numerical_query.value,
+                    )
+                }),
+                PrefixType::ProcessGroupId => {
+                    process.process_group_id.map_or(false, |process_group_id| {
+                        matches_condition(
+                            &numerical_query.condition,
+                            process_group_id as f64,
+                            numerical_query.value,
+                        )
+                    })
+                }
+                PrefixType::MinorFaults => matches_condition(
+                    &numerical_query.condition,
+                    process.minor_faults_per_sec,
+                    numerical_query.value,
+                ),
+                PrefixType::MajorFaults => matches_condition(
+                    &numerical_query.condition,
+                    process.major_faults_per_sec,
+                    numerical_query.value,
+                ),
+                PrefixType::VoluntarySwitches => matches_condition(
+                    &numerical_query.condition,
+                    process.voluntary_switches_per_sec,
+                    numerical_query.value,
+                ),
+                PrefixType::InvoluntarySwitches => matches_condition(
+                    &numerical_query.condition,
+                    process.involuntary_switches_per_sec,
+                    numerical_query.value,
+                ),
                 _ => true,
             }
         } else {
@@ -777,3 +890,83 @@ pub struct NumericalQuery {
     pub condition: QueryComparison,
     pub value: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(query: &str) -> Query {
+        let mut proc_widget_state =
+            ProcWidgetState::init(false, false, false, false, false, false, false, false, &[]);
+        proc_widget_state
+            .process_search_state
+            .search_state
+            .current_search_query = query.to_string();
+
+        proc_widget_state.parse_query().unwrap()
+    }
+
+    #[test]
+    fn test_oom_query() {
+        let process = |oom_score, oom_score_adj| ConvertedProcessData {
+            oom_score,
+            oom_score_adj,
+            ..Default::default()
+        };
+
+        let query = parse("oom > 100");
+        assert!(query.check(&process(Some(200), Some(0)), false));
+        assert!(!query.check(&process(Some(100), Some(0)), false));
+        assert!(!query.check(&process(None, Some(0)), false));
+
+        let query = parse("oomadj < -500");
+        assert!(query.check(&process(Some(0), Some(-1000)), false));
+        assert!(!query.check(&process(Some(0), Some(-500)), false));
+        assert!(!query.check(&process(Some(0), Some(500)), false));
+        assert!(!query.check(&process(Some(0), None), false));
+
+        let query = parse("oomadj>=-500");
+        assert!(query.check(&process(Some(0), Some(-500)), false));
+        assert!(!query.check(&process(Some(0), Some(-501)), false));
+    }
+
+    #[test]
+    fn test_kernel_thread_query() {
+        let process = |is_kernel_thread| ConvertedProcessData {
+            is_kernel_thread,
+            ..Default::default()
+        };
+
+        let query = parse("kthread = true");
+        assert!(query.check(&process(true), false));
+        assert!(!query.check(&process(false), false));
+
+        let query = parse("kthread = false");
+        assert!(query.check(&process(false), false));
+        assert!(!query.check(&process(true), false));
+    }
+
+    #[test]
+    fn test_session_and_process_group_query() {
+        let process = |session_id, process_group_id| ConvertedProcessData {
+            session_id,
+            process_group_id,
+            ..Default::default()
+        };
+
+        let query = parse("pgid = 1");
+        assert!(query.check(&process(None, Some(1)), false));
+        assert!(!query.check(&process(None, Some(10)), false));
+        assert!(!query.check(&process(None, Some(100)), false));
+        assert!(!query.check(&process(None, None), false));
+
+        let query = parse("sid = 12");
+        assert!(query.check(&process(Some(12), None), false));
+        assert!(!query.check(&process(Some(1), None), false));
+        assert!(!query.check(&process(Some(123), None), false));
+
+        let query = parse("sid > 100");
+        assert!(query.check(&process(Some(123), None), false));
+        assert!(!query.check(&process(Some(100), None), false));
+    }
+}
@@ -9,6 +9,8 @@ use crate::{
     constants,
     data_harvester::processes::{self, ProcessSorting},
 };
+
+use super::process_files::OpenFile;
 use ProcessSorting::*;
 
 #[derive(Debug)]
@@ -57,10 +59,48 @@ impl Default for KillSignal {
     }
 }
 
+/// What the kill dialog sends its signal to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KillTarget {
+    /// Just the selected process(es).
+    Process,
+    /// The selected process(es) and all of their descendants.  This is only offered in tree mode.
+    Subtree,
+    /// Every process in the process group(s) of the selected process(es).
+    #[cfg(target_family = "unix")]
+    ProcessGroup,
+    /// Every process in the session(s) of the selected process(es).
+    #[cfg(target_family = "unix")]
+    Session,
+}
+
+impl Default for KillTarget {
+    fn default() -> Self {
+        KillTarget::Process
+    }
+}
+
+impl KillTarget {
+    pub fn next(self, is_tree_mode: bool) -> Self {
+        match self {
+            KillTarget::Process if is_tree_mode => KillTarget::Subtree,
+            #[cfg(target_family = "unix")]
+            KillTarget::Process | KillTarget::Subtree => KillTarget::ProcessGroup,
+            #[cfg(target_family = "unix")]
+            KillTarget::ProcessGroup => KillTarget::Session,
+            #[cfg(target_family = "unix")]
+            KillTarget::Session => KillTarget::Process,
+            #[cfg(target_os = "windows")]
+            KillTarget::Process | KillTarget::Subtree => KillTarget::Process,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct AppDeleteDialogState {
     pub is_showing_dd: bool,
     pub selected_signal: KillSignal,
+    pub kill_target: KillTarget,
     /// tl x, tl y, br x, br y, index/signal
     pub button_positions: Vec<(u16, u16, u16, u16, usize)>,
     pub keyboard_signal_select: usize,
@@ -68,6 +108,66 @@ pub struct AppDeleteDialogState {
     pub scroll_pos: usize,
 }
 
+#[derive(Default)]
+pub struct AppAffinityDialogState {
+    pub is_showing_affinity: bool,
+    /// Whether each CPU is selected, indexed by CPU number.
+    pub selected_cpus: Vec<bool>,
+    pub current_cpu: usize,
+    /// tl x, tl y, br x, br y, CPU
+    pub button_positions: Vec<(u16, u16, u16, u16, usize)>,
+    pub scroll_pos: usize,
+}
+
+impl AppAffinityDialogState {
+    pub fn toggle_current_cpu(&mut self) {
+        if let Some(selected) = self.selected_cpus.get_mut(self.current_cpu) {
+            *selected = !*selected;
+        }
+    }
+
+    /// Selects every CPU, or deselects them all if they are all already selected.
+    pub fn toggle_all_cpus(&mut self) {
+        let select_all = !self.selected_cpus.iter().all(|selected| *selected);
+        self.selected_cpus
+            .iter_mut()
+            .for_each(|selected| *selected = select_all);
+    }
+}
+
+#[derive(Default)]
+pub struct AppOomDialogState {
+    pub is_showing_oom: bool,
+    /// The `oom_score_adj` to apply to the selected process(es).
+    pub oom_score_adj: i32,
+}
+
+#[derive(Default)]
+pub struct AppFilesDialogState {
+    pub is_showing_files: bool,
+    pub files: Vec<OpenFile>,
+    /// Filters the listed files by their target or type.
+    pub search_query: String,
+    /// The index of the highlighted file, within the filtered files.
+    pub current_index: usize,
+    pub scroll_pos: usize,
+}
+
+impl AppFilesDialogState {
+    /// Returns the files whose target or type contain the search query, ignoring case.
+    pub fn get_filtered_files(&self) -> Vec<&OpenFile> {
+        let query = self.search_query.to_lowercase();
+        self.files
+            .iter()
+            .filter(|file| {
+                query.is_empty()
+                    || file.target.to_lowercase().contains(&query)
+                    || file.file_type.contains(&query)
+            })
+            .collect()
+    }
+}
+
 pub struct AppHelpDialogState {
     pub is_showing_help: bool,
     pub scroll_state: ParagraphScrollState,
@@ -196,7 +296,7 @@ pub struct ProcColumn {
 
 impl Default for ProcColumn {
     fn default() -> Self {
-        let ordered_columns = vec![
+        let mut ordered_columns = vec![
             Count,
             Pid,
             ProcessName,
@@ -211,6 +311,7 @@ impl Default for ProcColumn {
             User,
             State,
         ];
+        ordered_columns.extend(processes::OPTIONAL_PROCESS_COLUMNS.iter().cloned());
 
         let mut column_mapping = HashMap::new();
         let mut longest_header_len = 0;
@@ -303,6 +404,18 @@ impl Default for ProcColumn {
                         },
                     );
                 }
+                ReadChars | WriteChars | ReadSyscalls | WriteSyscalls | CancelledWrite
+                | Affinity | LastCpu | OomScore | OomScoreAdj | Unit | MinorFaults
+                | MajorFaults | VoluntarySwitches | InvoluntarySwitches | FdCount | FdPercent
+                | DeletedCode | Tty | SessionId | ProcessGroupId => {
+                    column_mapping.insert(
+                        column,
+                        ColumnInfo {
+                            enabled: false,
+                            shortcut: None,
+                        },
+                    );
+                }
                 _ => {
                     column_mapping.insert(
                         column,
@@ -419,6 +532,14 @@ impl ProcColumn {
         self.backup_prev_scroll_position = self.previous_scroll_position;
     }
 
+    /// Returns the optional columns that are enabled, in display order.
+    pub fn get_enabled_optional_columns(&self) -> Vec<&ProcessSorting> {
+        processes::OPTIONAL_PROCESS_COLUMNS
+            .iter()
+            .filter(|column| self.is_enabled(column))
+            .collect()
+    }
+
     pub fn get_column_headers(
         &self, proc_sorting_type: &ProcessSorting, sort_reverse: bool,
     ) -> Vec<String> {
@@ -461,6 +582,8 @@ impl ProcColumn {
 pub struct ProcWidgetState {
     pub process_search_state: ProcessSearchState,
     pub is_grouped: bool,
+    /// Whether grouping is by systemd unit rather than by name.
+    pub is_grouped_by_unit: bool,
     pub scroll_state: AppScrollWidgetState,
     pub process_sorting_type: processes::ProcessSorting,
     pub is_process_sort_descending: bool,
@@ -471,12 +594,19 @@ pub struct ProcWidgetState {
     pub is_tree_mode: bool,
     pub table_width_state: CanvasTableWidthState,
     pub requires_redraw: bool,
+    pub is_io_graph_shown: bool,
+    /// Whether kernel threads are filtered out of the table.
+    pub is_kernel_threads_hidden: bool,
+    /// Whether only zombie (Z) and uninterruptible (D) processes are shown.
+    pub is_stuck_filter_enabled: bool,
 }
 
 impl ProcWidgetState {
+    #[allow(clippy::too_many_arguments)]
     pub fn init(
         is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool, is_grouped: bool,
         show_memory_as_values: bool, is_tree_mode: bool, is_using_command: bool,
+        is_kernel_threads_hidden: bool, process_columns: &[ProcessSorting],
     ) -> Self {
         let mut process_search_state = ProcessSearchState::default();
 
@@ -499,11 +629,18 @@ impl ProcWidgetState {
 
         // TODO: If we add customizable columns, this should pull from config
         let mut columns = ProcColumn::default();
+        for column in process_columns {
+            columns.try_enable(column);
+        }
         columns.set_to_sorted_index_from_type(&process_sorting_type);
         if is_grouped {
             // Normally defaults to showing by PID, toggle count on instead.
             columns.toggle(&ProcessSorting::Count);
             columns.toggle(&ProcessSorting::Pid);
+
+            // Grouped entries have no single state or user.
+            columns.try_disable(&ProcessSorting::State);
+            columns.try_disable(&ProcessSorting::User);
         }
         if show_memory_as_values {
             // Normally defaults to showing by percent, toggle value on instead.
@@ -518,6 +655,7 @@ impl ProcWidgetState {
         ProcWidgetState {
             process_search_state,
             is_grouped,
+            is_grouped_by_unit: false,
             scroll_state: AppScrollWidgetState::default(),
             process_sorting_type,
             is_process_sort_descending,
@@ -528,6 +666,9 @@ impl ProcWidgetState {
             is_tree_mode,
             table_width_state: CanvasTableWidthState::default(),
             requires_redraw: false,
+            is_io_graph_shown: false,
+            is_kernel_threads_hidden,
+            is_stuck_filter_enabled: false,
         }
     }
 
@@ -821,6 +962,42 @@ impl MemState {
     }
 }
 
+pub struct LoadAvgWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl LoadAvgWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        LoadAvgWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
+
+pub struct LoadAvgState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, LoadAvgWidgetState>,
+}
+
+impl LoadAvgState {
+    pub fn init(widget_states: HashMap<u64, LoadAvgWidgetState>) -> Self {
+        LoadAvgState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut LoadAvgWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&LoadAvgWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct TempWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub table_width_state: CanvasTableWidthState,
@@ -853,16 +1030,126 @@ impl TempState {
     }
 }
 
+pub struct NetInterfacesWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+}
+
+impl NetInterfacesWidgetState {
+    pub fn init() -> Self {
+        NetInterfacesWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+        }
+    }
+}
+
+pub struct NetInterfacesState {
+    pub widget_states: HashMap<u64, NetInterfacesWidgetState>,
+}
+
+impl NetInterfacesState {
+    pub fn init(widget_states: HashMap<u64, NetInterfacesWidgetState>) -> Self {
+        NetInterfacesState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(
+        &mut self, widget_id: u64,
+    ) -> Option<&mut NetInterfacesWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&NetInterfacesWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct SocketsWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+}
+
+impl SocketsWidgetState {
+    pub fn init() -> Self {
+        SocketsWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+        }
+    }
+}
+
+pub struct SocketsState {
+    pub widget_states: HashMap<u64, SocketsWidgetState>,
+}
+
+impl SocketsState {
+    pub fn init(widget_states: HashMap<u64, SocketsWidgetState>) -> Self {
+        SocketsState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut SocketsWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&SocketsWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct ProtocolsWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+    pub is_graph_shown: bool,
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl ProtocolsWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        ProtocolsWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+            is_graph_shown: false,
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
+
+pub struct ProtocolsState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, ProtocolsWidgetState>,
+}
+
+impl ProtocolsState {
+    pub fn init(widget_states: HashMap<u64, ProtocolsWidgetState>) -> Self {
+        ProtocolsState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut ProtocolsWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&ProtocolsWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct DiskWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub table_width_state: CanvasTableWidthState,
+    pub is_tree_mode: bool,
 }
 
 impl DiskWidgetState {
-    pub fn init() -> Self {
+    pub fn init(is_tree_mode: bool) -> Self {
         DiskWidgetState {
             scroll_state: AppScrollWidgetState::default(),
             table_width_state: CanvasTableWidthState::default(),
+            is_tree_mode,
         }
     }
 }
@@ -897,10 +1184,24 @@ pub struct BasicTableWidgetState {
     pub right_brc: Option<(u16, u16)>,
 }
 
-#[derive(Default)]
 pub struct BatteryWidgetState {
     pub currently_selected_battery_index: usize,
     pub tab_click_locs: Option<Vec<((u16, u16), (u16, u16))>>,
+    pub is_graph_mode: bool,
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl BatteryWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        BatteryWidgetState {
+            currently_selected_battery_index: 0,
+            tab_click_locs: None,
+            is_graph_mode: false,
+            current_display_time,
+            autohide_timer,
+        }
+    }
 }
 
 pub struct BatteryState {
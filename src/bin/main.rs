@@ -169,6 +169,13 @@ fn main() -> Result<()> {
                             app.canvas_data.network_data_tx = network_data.tx;
                             app.canvas_data.rx_display = network_data.rx_display;
                             app.canvas_data.tx_display = network_data.tx_display;
+                            app.canvas_data.network_data_rx_packets = network_data.rx_packets;
+                            app.canvas_data.network_data_tx_packets = network_data.tx_packets;
+                            app.canvas_data.rx_packets_display = network_data.rx_packets_display;
+                            app.canvas_data.tx_packets_display = network_data.tx_packets_display;
+                            app.canvas_data.errors_display = network_data.errors_display;
+                            app.canvas_data.drops_display = network_data.drops_display;
+                            app.canvas_data.wireless_displays = network_data.wireless_displays;
                             if let Some(total_rx_display) = network_data.total_rx_display {
                                 app.canvas_data.total_rx_display = total_rx_display;
                             }
@@ -177,9 +184,41 @@ fn main() -> Result<()> {
                             }
                         }
 
+                        // Network interfaces
+                        if app.used_widgets.use_net_interfaces {
+                            app.canvas_data.interface_data = convert_interface_row(
+                                &app.data_collection,
+                                &app.app_config_fields.network_unit_type,
+                                app.app_config_fields.network_use_binary_prefix,
+                            );
+                        }
+
+                        // Protocols
+                        if app.used_widgets.use_protocols {
+                            app.canvas_data.protocol_data =
+                                convert_protocol_row(&app.data_collection);
+                            app.canvas_data.tcp_retransmit_data =
+                                convert_tcp_retransmit_data_points(&app.data_collection, false);
+                        }
+
+                        // Sockets
+                        if app.used_widgets.use_sockets {
+                            app.canvas_data.socket_data = convert_socket_row(&app.data_collection);
+                        }
+
                         // Disk
                         if app.used_widgets.use_disk {
-                            app.canvas_data.disk_data = convert_disk_row(&app.data_collection);
+                            for (widget_id, disk_widget_state) in &app.disk_state.widget_states {
+                                let (disk_data, disk_full_warnings) = convert_disk_row(
+                                    &app.data_collection,
+                                    disk_widget_state.is_tree_mode,
+                                    app.app_config_fields.disk_full_warning,
+                                );
+                                app.canvas_data.disk_data.insert(*widget_id, disk_data);
+                                app.canvas_data
+                                    .disk_full_warnings
+                                    .insert(*widget_id, disk_full_warnings);
+                            }
                         }
 
                         // Temperatures
@@ -212,11 +251,27 @@ fn main() -> Result<()> {
                             app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
                         }
 
+                        // Load average
+                        if app.used_widgets.use_load_avg {
+                            app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
+                            app.canvas_data.load_avg_points = convert_load_avg_data_points(
+                                &app.data_collection,
+                                false,
+                                app.app_config_fields.normalize_load_avg,
+                            );
+                        }
+
                         // Processes
                         if app.used_widgets.use_proc {
                             update_all_process_lists(&mut app);
                         }
 
+                        // Summary
+                        if app.used_widgets.use_summary {
+                            app.canvas_data.summary_data =
+                                convert_summary_data(&app.data_collection);
+                        }
+
                         // Battery
                         #[cfg(feature = "battery")]
                         {
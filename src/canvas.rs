@@ -13,6 +13,7 @@ use tui::{
 
 use canvas_colours::*;
 use dialogs::*;
+use drawing_utils::get_dialog_draw_loc;
 use screens::*;
 use widgets::*;
 
@@ -23,7 +24,9 @@ use crate::{
         App,
     },
     constants::*,
-    data_conversion::{ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData},
+    data_conversion::{
+        ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData, ConvertedSummaryData,
+    },
     options::Config,
     utils::error,
     utils::error::BottomError,
@@ -47,7 +50,19 @@ pub struct DisplayableData {
     pub total_tx_display: String,
     pub network_data_rx: Vec<Point>,
     pub network_data_tx: Vec<Point>,
-    pub disk_data: Vec<Vec<String>>,
+    pub network_data_rx_packets: Vec<Point>,
+    pub network_data_tx_packets: Vec<Point>,
+    pub rx_packets_display: String,
+    pub tx_packets_display: String,
+    pub errors_display: String,
+    pub drops_display: String,
+    pub wireless_displays: Vec<String>,
+    pub disk_data: HashMap<u64, Vec<Vec<String>>>, // Key is the widget ID.
+    pub interface_data: Vec<Vec<String>>,
+    pub protocol_data: Vec<Vec<String>>,
+    pub socket_data: Vec<Vec<String>>,
+    pub tcp_retransmit_data: Vec<Point>,
+    pub disk_full_warnings: HashMap<u64, Vec<bool>>, // Key is the widget ID.
     pub temp_sensor_data: Vec<Vec<String>>,
     pub single_process_data: HashMap<Pid, ConvertedProcessData>, // Contains single process data, key is PID
     pub finalized_process_data_map: HashMap<u64, Vec<ConvertedProcessData>>, // What's actually displayed, key is the widget ID.
@@ -59,8 +74,10 @@ pub struct DisplayableData {
     pub mem_data: Vec<Point>, // TODO: Switch this and all data points over to a better data structure...
     pub swap_data: Vec<Point>,
     pub load_avg_data: [f32; 3],
+    pub load_avg_points: [Vec<Point>; 3],
     pub cpu_data: Vec<ConvertedCpuData>,
     pub battery_data: Vec<ConvertedBatteryData>,
+    pub summary_data: ConvertedSummaryData,
 }
 
 #[derive(Debug)]
@@ -330,6 +347,9 @@ impl Painter {
                 // Reset dd_dialog...
                 app_state.delete_dialog_state.button_positions = vec![];
 
+                // Reset affinity dialog...
+                app_state.affinity_dialog_state.button_positions = vec![];
+
                 // Reset battery dialog...
                 for battery_widget in app_state.battery_state.widget_states.values_mut() {
                     battery_widget.tab_click_locs = None;
@@ -373,6 +393,21 @@ impl Painter {
                     .split(vertical_dialog_chunk[1]);
 
                 self.draw_help_dialog(f, app_state, middle_dialog_chunk[1]);
+            } else if app_state.affinity_dialog_state.is_showing_affinity {
+                let affinity_text = self.get_affinity_spans(app_state);
+                let dialog_loc = get_dialog_draw_loc(terminal_size, 22);
+                app_state.affinity_dialog_state.is_showing_affinity =
+                    self.draw_affinity_dialog(f, affinity_text, app_state, dialog_loc);
+            } else if app_state.oom_dialog_state.is_showing_oom {
+                let oom_text = self.get_oom_spans(app_state);
+                let dialog_loc = get_dialog_draw_loc(terminal_size, 11);
+                app_state.oom_dialog_state.is_showing_oom =
+                    self.draw_oom_dialog(f, oom_text, app_state, dialog_loc);
+            } else if app_state.files_dialog_state.is_showing_files {
+                let files_text = self.get_files_spans(app_state);
+                let dialog_loc = get_dialog_draw_loc(terminal_size, terminal_height * 3 / 4);
+                app_state.files_dialog_state.is_showing_files =
+                    self.draw_files_dialog(f, files_text, app_state, dialog_loc);
             } else if app_state.delete_dialog_state.is_showing_dd {
                 // TODO: This needs the paragraph wrap feature from tui-rs to be pushed to complete... but for now it's pretty close!
                 // The main problem right now is that I cannot properly calculate the height offset since
@@ -388,12 +423,13 @@ impl Painter {
                     terminal_width * 50 / 100
                 };
 
+                // This leaves a couple of lines for the kill target, which may list PIDs.
                 let text_height = if cfg!(target_os = "windows")
                     || !app_state.app_config_fields.is_advanced_kill
                 {
-                    7
+                    9
                 } else {
-                    22
+                    24
                 };
 
                 // let (text_width, text_height) = if let Some(dd_text) = &dd_text {
@@ -473,6 +509,15 @@ impl Painter {
                         rect[0],
                         app_state.current_widget.widget_id,
                     ),
+                    LoadAvg => self.draw_load_avg_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    Summary => {
+                        self.draw_summary(f, app_state, rect[0], app_state.current_widget.widget_id)
+                    }
                     Disk => self.draw_disk_table(
                         f,
                         app_state,
@@ -487,6 +532,27 @@ impl Painter {
                         true,
                         app_state.current_widget.widget_id,
                     ),
+                    NetInterfaces => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Protocols => self.draw_protocols(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Sockets => self.draw_socket_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
                     Net => self.draw_network_graph(
                         f,
                         app_state,
@@ -732,6 +798,10 @@ impl Painter {
                     Empty => {}
                     Cpu => self.draw_cpu(f, app_state, *widget_draw_loc, widget.widget_id),
                     Mem => self.draw_memory_graph(f, app_state, *widget_draw_loc, widget.widget_id),
+                    LoadAvg => {
+                        self.draw_load_avg_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    Summary => self.draw_summary(f, app_state, *widget_draw_loc, widget.widget_id),
                     Net => self.draw_network(f, app_state, *widget_draw_loc, widget.widget_id),
                     Temp => {
                         self.draw_temp_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
@@ -739,6 +809,23 @@ impl Painter {
                     Disk => {
                         self.draw_disk_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
                     }
+                    NetInterfaces => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    Protocols => {
+                        self.draw_protocols(f, app_state, *widget_draw_loc, true, widget.widget_id)
+                    }
+                    Sockets => self.draw_socket_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
                     Proc => self.draw_process_features(
                         f,
                         app_state,
@@ -26,6 +26,11 @@ pub struct CanvasColours {
     pub high_battery_colour: Style,
     pub medium_battery_colour: Style,
     pub low_battery_colour: Style,
+    pub disk_full_warning_style: Style,
+    pub zombie_process_style: Style,
+    pub uninterruptible_process_style: Style,
+    pub fd_warning_style: Style,
+    pub stopped_process_style: Style,
     pub invalid_query_style: Style,
     pub disabled_text_style: Style,
 }
@@ -69,6 +74,11 @@ impl Default for CanvasColours {
             high_battery_colour: Style::default().fg(Color::Green),
             medium_battery_colour: Style::default().fg(Color::Yellow),
             low_battery_colour: Style::default().fg(Color::Red),
+            disk_full_warning_style: Style::default().fg(Color::Red),
+            zombie_process_style: Style::default().fg(Color::Magenta),
+            uninterruptible_process_style: Style::default().fg(Color::Yellow),
+            fd_warning_style: Style::default().fg(Color::Red),
+            stopped_process_style: Style::default().fg(Color::Blue),
             invalid_query_style: Style::default().fg(tui::style::Color::Red),
             disabled_text_style: Style::default().fg(Color::DarkGray),
         }
@@ -167,6 +177,31 @@ impl CanvasColours {
                 .context("Update 'low_battery_color' in your config file.")?;
         }
 
+        if let Some(disk_full_warning_color) = &colours.disk_full_warning_color {
+            self.set_disk_full_warning_color(disk_full_warning_color)
+                .context("Update 'disk_full_warning_color' in your config file.")?;
+        }
+
+        if let Some(zombie_process_color) = &colours.zombie_process_color {
+            self.set_zombie_process_color(zombie_process_color)
+                .context("Update 'zombie_process_color' in your config file.")?;
+        }
+
+        if let Some(uninterruptible_process_color) = &colours.uninterruptible_process_color {
+            self.set_uninterruptible_process_color(uninterruptible_process_color)
+                .context("Update 'uninterruptible_process_color' in your config file.")?;
+        }
+
+        if let Some(fd_warning_color) = &colours.fd_warning_color {
+            self.set_fd_warning_color(fd_warning_color)
+                .context("Update 'fd_warning_color' in your config file.")?;
+        }
+
+        if let Some(stopped_process_color) = &colours.stopped_process_color {
+            self.set_stopped_process_color(stopped_process_color)
+                .context("Update 'stopped_process_color' in your config file.")?;
+        }
+
         if let Some(disabled_text_color) = &colours.disabled_text_color {
             self.set_disabled_text_colour(disabled_text_color)
                 .context("Update 'disabled_text_color' in your config file.")?;
@@ -298,4 +333,29 @@ impl CanvasColours {
         self.low_battery_colour = get_style_from_config(colour)?;
         Ok(())
     }
+
+    pub fn set_disk_full_warning_color(&mut self, colour: &str) -> error::Result<()> {
+        self.disk_full_warning_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_zombie_process_color(&mut self, colour: &str) -> error::Result<()> {
+        self.zombie_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_uninterruptible_process_color(&mut self, colour: &str) -> error::Result<()> {
+        self.uninterruptible_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_fd_warning_color(&mut self, colour: &str) -> error::Result<()> {
+        self.fd_warning_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_stopped_process_color(&mut self, colour: &str) -> error::Result<()> {
+        self.stopped_process_style = get_style_from_config(colour)?;
+        Ok(())
+    }
 }
@@ -1,5 +1,11 @@
+pub mod affinity_dialog;
 pub mod dd_dialog;
+pub mod files_dialog;
 pub mod help_dialog;
+pub mod oom_dialog;
 
+pub use affinity_dialog::AffinityDialog;
 pub use dd_dialog::KillDialog;
+pub use files_dialog::FilesDialog;
 pub use help_dialog::HelpDialog;
+pub use oom_dialog::OomDialog;
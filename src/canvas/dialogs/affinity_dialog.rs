@@ -0,0 +1,226 @@
+use std::cmp::min;
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::{app::App, canvas::Painter};
+
+const AFFINITY_BASE: &str = " Set CPU Affinity ── Esc to close ";
+const AFFINITY_ERROR_BASE: &str = " Error ── Esc to close ";
+
+pub trait AffinityDialog {
+    fn get_affinity_spans(&self, app_state: &App) -> Option<Text<'_>>;
+
+    fn draw_affinity_cpu_list<B: Backend>(
+        &self, f: &mut Frame<'_, B>, list_draw_loc: &Rect, app_state: &mut App,
+    );
+
+    fn draw_affinity_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, affinity_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool;
+}
+
+impl AffinityDialog for Painter {
+    fn get_affinity_spans(&self, app_state: &App) -> Option<Text<'_>> {
+        if let Some(affinity_err) = &app_state.affinity_err {
+            return Some(Text::from(vec![
+                Spans::default(),
+                Spans::from("Failed to set CPU affinity."),
+                Spans::from(affinity_err.clone()),
+                Spans::from("Please press ENTER or ESC to close this dialog."),
+            ]));
+        } else if let Some(to_set_processes) = app_state.get_to_set_affinity_processes() {
+            if let Some(first_pid) = to_set_processes.1.first() {
+                let group_description =
+                    if app_state.is_grouped_by_unit(app_state.current_widget.widget_id) {
+                        "in the unit"
+                    } else {
+                        "with the name"
+                    };
+                return Some(Text::from(vec![
+                    Spans::from(""),
+                    if app_state.is_grouped(app_state.current_widget.widget_id) {
+                        if to_set_processes.1.len() != 1 {
+                            Spans::from(format!(
+                                "Set the CPU affinity of {} processes {} \"{}\".",
+                                to_set_processes.1.len(),
+                                group_description,
+                                to_set_processes.0
+                            ))
+                        } else {
+                            Spans::from(format!(
+                                "Set the CPU affinity of 1 process {} \"{}\".",
+                                group_description, to_set_processes.0
+                            ))
+                        }
+                    } else {
+                        Spans::from(format!(
+                            "Set the CPU affinity of process \"{}\" with PID {}.",
+                            to_set_processes.0, first_pid
+                        ))
+                    },
+                    Spans::from("Space toggles a CPU, a toggles all CPUs, ENTER confirms."),
+                ]));
+            }
+        }
+
+        None
+    }
+
+    fn draw_affinity_cpu_list<B: Backend>(
+        &self, f: &mut Frame<'_, B>, list_draw_loc: &Rect, app_state: &mut App,
+    ) {
+        let list_rect = Layout::default()
+            .direction(Direction::Horizontal)
+            .margin(1)
+            .constraints(
+                [
+                    Constraint::Length(list_draw_loc.width.saturating_sub(14) / 2),
+                    Constraint::Min(0),
+                    Constraint::Length(list_draw_loc.width.saturating_sub(14) / 2),
+                ]
+                .as_ref(),
+            )
+            .split(*list_draw_loc)[1];
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); list_rect.height.into()])
+            .split(list_rect);
+
+        let affinity_dialog_state = &mut app_state.affinity_dialog_state;
+        let current_cpu = affinity_dialog_state.current_cpu;
+        let prev_offset = affinity_dialog_state.scroll_pos;
+        affinity_dialog_state.scroll_pos = if current_cpu < prev_offset {
+            current_cpu
+        } else if current_cpu >= prev_offset + layout.len() {
+            current_cpu + 1 - layout.len()
+        } else {
+            prev_offset
+        };
+        let scroll_offset = affinity_dialog_state.scroll_pos;
+
+        let end = min(
+            scroll_offset + layout.len(),
+            affinity_dialog_state.selected_cpus.len(),
+        );
+        affinity_dialog_state.button_positions = layout
+            .iter()
+            .zip(scroll_offset..end)
+            .map(|(pos, cpu)| {
+                (
+                    pos.x,
+                    pos.y,
+                    pos.x + pos.width - 1,
+                    pos.y + pos.height - 1,
+                    cpu,
+                )
+            })
+            .collect::<Vec<(u16, u16, u16, u16, usize)>>();
+
+        let buttons = (scroll_offset..end).map(|cpu| {
+            let text = format!(
+                "[{}] CPU {}",
+                if affinity_dialog_state.selected_cpus[cpu] {
+                    "x"
+                } else {
+                    " "
+                },
+                cpu
+            );
+            if cpu == current_cpu {
+                Span::styled(text, self.colours.currently_selected_text_style)
+            } else {
+                Span::raw(text)
+            }
+        });
+
+        for (btn, pos) in buttons.zip(layout.into_iter()) {
+            f.render_widget(Paragraph::new(btn).alignment(Alignment::Left), pos);
+        }
+    }
+
+    fn draw_affinity_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, affinity_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool {
+        if let Some(affinity_text) = affinity_text {
+            let affinity_title = if app_state.affinity_err.is_some() {
+                Spans::from(vec![
+                    Span::styled(" Error ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(AFFINITY_ERROR_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(vec![
+                    Span::styled(" Set CPU Affinity ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(AFFINITY_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            };
+
+            f.render_widget(
+                Paragraph::new(affinity_text)
+                    .block(
+                        Block::default()
+                            .title(affinity_title)
+                            .style(self.colours.border_style)
+                            .borders(Borders::ALL)
+                            .border_style(self.colours.border_style),
+                    )
+                    .style(self.colours.text_style)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+
+            // Now draw the CPU list if needed...
+            let split_draw_loc = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    if app_state.affinity_err.is_some() {
+                        vec![Constraint::Percentage(100)]
+                    } else {
+                        vec![Constraint::Length(5), Constraint::Min(3)]
+                    }
+                    .as_ref(),
+                )
+                .split(draw_loc);
+
+            // This being true implies that affinity_err is none.
+            if let Some(list_draw_loc) = split_draw_loc.get(1) {
+                self.draw_affinity_cpu_list(f, list_draw_loc, app_state);
+            }
+
+            if app_state.affinity_err.is_some() {
+                return app_state.affinity_dialog_state.is_showing_affinity;
+            } else {
+                return true;
+            }
+        }
+
+        // As with the kill dialog, we just return "false" if we can't find the process.
+        false
+    }
+}
@@ -9,13 +9,35 @@ use tui::{
 };
 
 use crate::{
-    app::{App, KillSignal},
+    app::{App, KillSignal, KillTarget},
     canvas::Painter,
+    Pid,
 };
 
 const DD_BASE: &str = " Confirm Kill Process ── Esc to close ";
 const DD_ERROR_BASE: &str = " Error ── Esc to close ";
 
+/// How many PIDs or process groups to list in the kill dialog before summarizing the rest.
+const MAX_LISTED_PIDS: usize = 24;
+
+fn get_pid_list_string(pids: &[Pid]) -> String {
+    if pids.is_empty() {
+        "none found".to_string()
+    } else {
+        let listed = pids
+            .iter()
+            .take(MAX_LISTED_PIDS)
+            .map(|pid| pid.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if pids.len() > MAX_LISTED_PIDS {
+            format!("{}, and {} more", listed, pids.len() - MAX_LISTED_PIDS)
+        } else {
+            listed
+        }
+    }
+}
+
 pub trait KillDialog {
     fn get_dd_spans(&self, app_state: &App) -> Option<Text<'_>>;
 
@@ -33,25 +55,54 @@ impl KillDialog for Painter {
         if let Some(dd_err) = &app_state.dd_err {
             return Some(Text::from(vec![
                 Spans::default(),
-                Spans::from("Failed to kill process."),
+                Spans::from("Failed to signal process."),
                 Spans::from(dd_err.clone()),
                 Spans::from("Please press ENTER or ESC to close this dialog."),
             ]));
         } else if let Some(to_kill_processes) = app_state.get_to_delete_processes() {
             if let Some(first_pid) = to_kill_processes.1.first() {
+                let group_description =
+                    if app_state.is_grouped_by_unit(app_state.current_widget.widget_id) {
+                        "in the unit"
+                    } else {
+                        "with the name"
+                    };
+                let target_description = match app_state.delete_dialog_state.kill_target {
+                    KillTarget::Process => "just the selected process(es)".to_string(),
+                    KillTarget::Subtree => {
+                        let subtree = app_state.get_to_delete_subtree();
+                        format!(
+                            "the process(es) and all descendants, {} in total, bottom-up: {}",
+                            subtree.len(),
+                            get_pid_list_string(&subtree)
+                        )
+                    }
+                    #[cfg(target_family = "unix")]
+                    KillTarget::ProcessGroup => format!(
+                        "process group(s) {}",
+                        get_pid_list_string(&app_state.get_to_delete_process_groups())
+                    ),
+                    #[cfg(target_family = "unix")]
+                    KillTarget::Session => format!(
+                        "every process group in the session(s): {}",
+                        get_pid_list_string(&app_state.get_to_delete_process_groups())
+                    ),
+                };
+
                 return Some(Text::from(vec![
                     Spans::from(""),
                     if app_state.is_grouped(app_state.current_widget.widget_id) {
                         if to_kill_processes.1.len() != 1 {
                             Spans::from(format!(
-                                "Kill {} processes with the name \"{}\"?  Press ENTER to confirm.",
+                                "Kill {} processes {} \"{}\"?  Press ENTER to confirm.",
                                 to_kill_processes.1.len(),
+                                group_description,
                                 to_kill_processes.0
                             ))
                         } else {
                             Spans::from(format!(
-                                "Kill 1 process with the name \"{}\"?  Press ENTER to confirm.",
-                                to_kill_processes.0
+                                "Kill 1 process {} \"{}\"?  Press ENTER to confirm.",
+                                group_description, to_kill_processes.0
                             ))
                         }
                     } else {
@@ -60,6 +111,10 @@ impl KillDialog for Painter {
                             to_kill_processes.0, first_pid
                         ))
                     },
+                    Spans::from(format!(
+                        "Target: {} (press t to change)",
+                        target_description
+                    )),
                 ]));
             }
         }
@@ -0,0 +1,201 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Paragraph, Row, Table, Wrap},
+};
+
+use crate::{app::App, canvas::Painter};
+
+const FILES_BASE: &str = " Open Files ── Esc to close ";
+const FILES_ERROR_BASE: &str = " Error ── Esc to close ";
+
+pub trait FilesDialog {
+    fn get_files_spans(&self, app_state: &App) -> Option<Text<'_>>;
+
+    fn draw_files_list<B: Backend>(
+        &self, f: &mut Frame<'_, B>, list_draw_loc: &Rect, app_state: &mut App,
+    );
+
+    fn draw_files_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, files_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool;
+}
+
+impl FilesDialog for Painter {
+    fn get_files_spans(&self, app_state: &App) -> Option<Text<'_>> {
+        if let Some(files_err) = &app_state.files_err {
+            return Some(Text::from(vec![
+                Spans::default(),
+                Spans::from("Failed to list open files."),
+                Spans::from(files_err.clone()),
+                Spans::from("Please press ENTER or ESC to close this dialog."),
+            ]));
+        } else if let Some((name, pid)) = app_state.get_to_show_files_process() {
+            let files_dialog_state = &app_state.files_dialog_state;
+            return Some(Text::from(vec![
+                Spans::from(""),
+                Spans::from(format!(
+                    "{} of {} files open by process \"{}\" with PID {}.",
+                    files_dialog_state.get_filtered_files().len(),
+                    files_dialog_state.files.len(),
+                    name,
+                    pid
+                )),
+                Spans::from(vec![
+                    Span::styled("Search: ", self.colours.table_header_style),
+                    Span::raw(files_dialog_state.search_query.clone()),
+                ]),
+            ]));
+        }
+
+        None
+    }
+
+    fn draw_files_list<B: Backend>(
+        &self, f: &mut Frame<'_, B>, list_draw_loc: &Rect, app_state: &mut App,
+    ) {
+        let list_rect = Layout::default()
+            .direction(Direction::Horizontal)
+            .margin(1)
+            .constraints([Constraint::Percentage(100)].as_ref())
+            .split(*list_draw_loc)[0];
+
+        // Leave room for the header row.
+        let num_rows = usize::from(list_rect.height.saturating_sub(1));
+
+        let files_dialog_state = &mut app_state.files_dialog_state;
+        let current_index = files_dialog_state.current_index;
+        let prev_offset = files_dialog_state.scroll_pos;
+        files_dialog_state.scroll_pos = if current_index < prev_offset {
+            current_index
+        } else if current_index >= prev_offset + num_rows {
+            (current_index + 1).saturating_sub(num_rows)
+        } else {
+            prev_offset
+        };
+        let scroll_offset = files_dialog_state.scroll_pos;
+
+        let rows = files_dialog_state
+            .get_filtered_files()
+            .into_iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(num_rows)
+            .map(|(index, file)| {
+                let row = Row::new(vec![
+                    file.fd.to_string(),
+                    file.file_type.to_string(),
+                    file.flags.clone().unwrap_or_else(|| "N/A".to_string()),
+                    file.position
+                        .map(|position| position.to_string())
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    file.target.clone(),
+                ]);
+
+                if index == current_index {
+                    row.style(self.colours.currently_selected_text_style)
+                } else {
+                    row.style(self.colours.text_style)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        f.render_widget(
+            Table::new(rows)
+                .header(
+                    Row::new(vec!["FD", "Type", "Flags", "Pos", "Target"])
+                        .style(self.colours.table_header_style),
+                )
+                .widths(&[
+                    Constraint::Length(6),
+                    Constraint::Length(11),
+                    Constraint::Length(20),
+                    Constraint::Length(10),
+                    Constraint::Min(0),
+                ]),
+            list_rect,
+        );
+    }
+
+    fn draw_files_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, files_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool {
+        if let Some(files_text) = files_text {
+            let files_title = if app_state.files_err.is_some() {
+                Spans::from(vec![
+                    Span::styled(" Error ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(FILES_ERROR_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(vec![
+                    Span::styled(" Open Files ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(FILES_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            };
+
+            f.render_widget(
+                Paragraph::new(files_text)
+                    .block(
+                        Block::default()
+                            .title(files_title)
+                            .style(self.colours.border_style)
+                            .borders(Borders::ALL)
+                            .border_style(self.colours.border_style),
+                    )
+                    .style(self.colours.text_style)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+
+            // Now draw the file list if needed...
+            let split_draw_loc = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    if app_state.files_err.is_some() {
+                        vec![Constraint::Percentage(100)]
+                    } else {
+                        vec![Constraint::Length(4), Constraint::Min(3)]
+                    }
+                    .as_ref(),
+                )
+                .split(draw_loc);
+
+            // This being true implies that files_err is none.
+            if let Some(list_draw_loc) = split_draw_loc.get(1) {
+                self.draw_files_list(f, list_draw_loc, app_state);
+            }
+
+            if app_state.files_err.is_some() {
+                return app_state.files_dialog_state.is_showing_files;
+            } else {
+                return true;
+            }
+        }
+
+        // As with the kill dialog, we just return "false" if we can't find the process.
+        false
+    }
+}
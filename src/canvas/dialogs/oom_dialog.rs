@@ -0,0 +1,137 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    terminal::Frame,
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::{app::App, canvas::Painter};
+
+const OOM_BASE: &str = " Adjust OOM Score ── Esc to close ";
+const OOM_ERROR_BASE: &str = " Error ── Esc to close ";
+
+pub trait OomDialog {
+    fn get_oom_spans(&self, app_state: &App) -> Option<Text<'_>>;
+
+    fn draw_oom_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, oom_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool;
+}
+
+impl OomDialog for Painter {
+    fn get_oom_spans(&self, app_state: &App) -> Option<Text<'_>> {
+        if let Some(oom_err) = &app_state.oom_err {
+            return Some(Text::from(vec![
+                Spans::default(),
+                Spans::from("Failed to adjust the OOM score."),
+                Spans::from(oom_err.clone()),
+                Spans::from("Please press ENTER or ESC to close this dialog."),
+            ]));
+        } else if let Some(to_adjust_processes) = app_state.get_to_adjust_oom_processes() {
+            if let Some(first_pid) = to_adjust_processes.1.first() {
+                let group_description =
+                    if app_state.is_grouped_by_unit(app_state.current_widget.widget_id) {
+                        "in the unit"
+                    } else {
+                        "with the name"
+                    };
+                return Some(Text::from(vec![
+                    Spans::from(""),
+                    if app_state.is_grouped(app_state.current_widget.widget_id) {
+                        if to_adjust_processes.1.len() != 1 {
+                            Spans::from(format!(
+                                "Set the OOM score adjustment of {} processes {} \"{}\".",
+                                to_adjust_processes.1.len(),
+                                group_description,
+                                to_adjust_processes.0
+                            ))
+                        } else {
+                            Spans::from(format!(
+                                "Set the OOM score adjustment of 1 process {} \"{}\".",
+                                group_description, to_adjust_processes.0
+                            ))
+                        }
+                    } else {
+                        Spans::from(format!(
+                            "Set the OOM score adjustment of process \"{}\" with PID {}.",
+                            to_adjust_processes.0, first_pid
+                        ))
+                    },
+                    Spans::from(""),
+                    Spans::from(Span::styled(
+                        format!("< {} >", app_state.oom_dialog_state.oom_score_adj),
+                        self.colours.currently_selected_text_style,
+                    )),
+                    Spans::from(""),
+                    Spans::from("Up/Down changes by 100, Left/Right by 10, ENTER confirms."),
+                    Spans::from("-1000 stops the OOM killer from choosing the process."),
+                ]));
+            }
+        }
+
+        None
+    }
+
+    fn draw_oom_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, oom_text: Option<Text<'_>>, app_state: &mut App,
+        draw_loc: Rect,
+    ) -> bool {
+        if let Some(oom_text) = oom_text {
+            let oom_title = if app_state.oom_err.is_some() {
+                Spans::from(vec![
+                    Span::styled(" Error ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(OOM_ERROR_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(vec![
+                    Span::styled(" Adjust OOM Score ", self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to close ",
+                            "─".repeat(
+                                usize::from(draw_loc.width)
+                                    .saturating_sub(OOM_BASE.chars().count() + 2)
+                            )
+                        ),
+                        self.colours.border_style,
+                    ),
+                ])
+            };
+
+            f.render_widget(
+                Paragraph::new(oom_text)
+                    .block(
+                        Block::default()
+                            .title(oom_title)
+                            .style(self.colours.border_style)
+                            .borders(Borders::ALL)
+                            .border_style(self.colours.border_style),
+                    )
+                    .style(self.colours.text_style)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+
+            if app_state.oom_err.is_some() {
+                return app_state.oom_dialog_state.is_showing_oom;
+            } else {
+                return true;
+            }
+        }
+
+        // As with the kill dialog, we just return "false" if we can't find the process.
+        false
+    }
+}
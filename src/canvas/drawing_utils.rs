@@ -1,5 +1,6 @@
 use crate::app;
 use std::cmp::{max, min};
+use tui::layout::Rect;
 
 /// Return a (hard)-width vector for column widths.
 ///
@@ -195,6 +196,23 @@ pub fn calculate_basic_use_bars(use_percentage: f64, num_bars_available: usize)
     )
 }
 
+/// Returns where to draw a centred dialog of the given height.  Like the kill dialog, this takes up
+/// most of the width of small terminals and half of the width of larger ones.
+pub fn get_dialog_draw_loc(terminal_size: Rect, height: u16) -> Rect {
+    let width = if terminal_size.width < 100 {
+        terminal_size.width * 90 / 100
+    } else {
+        terminal_size.width * 50 / 100
+    };
+
+    Rect::new(
+        terminal_size.x + terminal_size.width.saturating_sub(width) / 2,
+        terminal_size.y + terminal_size.height.saturating_sub(height) / 2,
+        width,
+        min(height, terminal_size.height),
+    )
+}
+
 /// Interpolates between two points.  Mainly used to help fill in tui-rs blanks in certain situations.
 /// It is expected point_one is "further left" compared to point_two.
 /// A point is two floats, in (x, y) form.  x is time, y is value.
@@ -3,11 +3,16 @@ pub mod battery_display;
 pub mod cpu_basic;
 pub mod cpu_graph;
 pub mod disk_table;
+pub mod load_avg_graph;
 pub mod mem_basic;
 pub mod mem_graph;
+pub mod net_interface_table;
 pub mod network_basic;
 pub mod network_graph;
 pub mod process_table;
+pub mod protocol_table;
+pub mod socket_table;
+pub mod summary;
 pub mod temp_table;
 
 pub use basic_table_arrows::BasicTableArrows;
@@ -15,9 +20,14 @@ pub use battery_display::BatteryDisplayWidget;
 pub use cpu_basic::CpuBasicWidget;
 pub use cpu_graph::CpuGraphWidget;
 pub use disk_table::DiskTableWidget;
+pub use load_avg_graph::LoadAvgGraphWidget;
 pub use mem_basic::MemBasicWidget;
 pub use mem_graph::MemGraphWidget;
+pub use net_interface_table::NetInterfaceTableWidget;
 pub use network_basic::NetworkBasicWidget;
 pub use network_graph::NetworkGraphWidget;
 pub use process_table::ProcessTableWidget;
+pub use protocol_table::ProtocolTableWidget;
+pub use socket_table::SocketTableWidget;
+pub use summary::SummaryWidget;
 pub use temp_table::TempTableWidget;
@@ -7,9 +7,10 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
+    symbols::Marker,
     terminal::Frame,
     text::{Span, Spans},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs},
+    widgets::{Axis, Block, Borders, Cell, Chart, Dataset, Paragraph, Row, Table, Tabs},
 };
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -112,7 +113,123 @@ impl BatteryDisplayWidget for Painter {
                 .direction(Direction::Horizontal)
                 .split(draw_loc)[0];
 
-            if let Some(battery_details) = app_state
+            if let (true, Some(battery_details)) = (
+                battery_widget_state.is_graph_mode,
+                app_state
+                    .canvas_data
+                    .battery_data
+                    .get(battery_widget_state.currently_selected_battery_index),
+            ) {
+                let time_start = -(battery_widget_state.current_display_time as f64);
+                let marker = if app_state.app_config_fields.use_dot {
+                    Marker::Dot
+                } else {
+                    Marker::Braille
+                };
+
+                // Only show the time labels on the bottom graph.
+                let is_time_shown = if app_state.app_config_fields.hide_time
+                    || (app_state.app_config_fields.autohide_time
+                        && battery_widget_state.autohide_timer.is_none())
+                {
+                    false
+                } else if let Some(time) = battery_widget_state.autohide_timer {
+                    if std::time::Instant::now().duration_since(time).as_millis()
+                        < AUTOHIDE_TIMEOUT_MILLISECONDS.into()
+                    {
+                        true
+                    } else {
+                        battery_widget_state.autohide_timer = None;
+                        false
+                    }
+                } else {
+                    draw_loc.height >= TIME_LABEL_HEIGHT_LIMIT
+                };
+                let x_axis = Axis::default().bounds([time_start, 0.0]);
+                let time_x_axis = if is_time_shown {
+                    x_axis.clone().style(self.colours.graph_style).labels(vec![
+                        Span::styled(
+                            format!("{}s", battery_widget_state.current_display_time / 1000),
+                            self.colours.graph_style,
+                        ),
+                        Span::styled("0s".to_string(), self.colours.graph_style),
+                    ])
+                } else {
+                    x_axis.clone()
+                };
+
+                let max_watts = battery_details
+                    .consumption_data
+                    .iter()
+                    .filter(|(time, _)| *time >= time_start)
+                    .map(|(_, watts)| *watts)
+                    .fold(1.0, f64::max)
+                    .ceil();
+
+                let inner_draw_loc = battery_block.inner(margined_draw_loc);
+                let graph_top = tab_draw_loc.y + 1 + table_gap;
+                let graph_draw_loc = Rect::new(
+                    inner_draw_loc.x,
+                    graph_top,
+                    inner_draw_loc.width,
+                    (inner_draw_loc.y + inner_draw_loc.height).saturating_sub(graph_top),
+                );
+                let graph_draw_locs = Layout::default()
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .direction(Direction::Vertical)
+                    .split(graph_draw_loc);
+
+                f.render_widget(battery_block, margined_draw_loc);
+                f.render_widget(
+                    Chart::new(vec![Dataset::default()
+                        .name(format!("Charge:{:.0}%", battery_details.charge_percentage))
+                        .marker(marker)
+                        .style(if battery_details.charge_percentage < 10.0 {
+                            self.colours.low_battery_colour
+                        } else if battery_details.charge_percentage < 50.0 {
+                            self.colours.medium_battery_colour
+                        } else {
+                            self.colours.high_battery_colour
+                        })
+                        .data(&battery_details.charge_data)
+                        .graph_type(tui::widgets::GraphType::Line)])
+                    .x_axis(x_axis)
+                    .y_axis(
+                        Axis::default()
+                            .style(self.colours.graph_style)
+                            .bounds([0.0, 100.5])
+                            .labels(vec![
+                                Span::styled("  0%", self.colours.graph_style),
+                                Span::styled("100%", self.colours.graph_style),
+                            ]),
+                    )
+                    .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                    graph_draw_locs[0],
+                );
+                f.render_widget(
+                    Chart::new(vec![Dataset::default()
+                        .name(format!("Power:{}", battery_details.watt_consumption))
+                        .marker(marker)
+                        .style(self.colours.graph_style)
+                        .data(&battery_details.consumption_data)
+                        .graph_type(tui::widgets::GraphType::Line)])
+                    .x_axis(time_x_axis)
+                    .y_axis(
+                        Axis::default()
+                            .style(self.colours.graph_style)
+                            .bounds([0.0, max_watts])
+                            .labels(vec![
+                                Span::styled(format!("{:>3}W", 0), self.colours.graph_style),
+                                Span::styled(
+                                    format!("{:>3}W", max_watts),
+                                    self.colours.graph_style,
+                                ),
+                            ]),
+                    )
+                    .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                    graph_draw_locs[1],
+                );
+            } else if let Some(battery_details) = app_state
                 .canvas_data
                 .battery_data
                 .get(battery_widget_state.currently_selected_battery_index)
@@ -140,6 +257,7 @@ impl BatteryDisplayWidget for Painter {
                             self.colours.high_battery_colour
                         }),
                     ]),
+                    Row::new(vec!["State", &battery_details.state]).style(self.colours.text_style),
                     Row::new(vec!["Consumption", &battery_details.watt_consumption])
                         .style(self.colours.text_style),
                     if let Some(duration_until_full) = &battery_details.duration_until_full {
@@ -154,6 +272,19 @@ impl BatteryDisplayWidget for Painter {
                     },
                     Row::new(vec!["Health %", &battery_details.health])
                         .style(self.colours.text_style),
+                    Row::new(vec!["Full capacity", &battery_details.energy_full])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Design capacity", &battery_details.energy_full_design])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Cycle count", &battery_details.cycle_count])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Voltage", &battery_details.voltage])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Technology", &battery_details.technology])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Vendor", &battery_details.vendor])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Model", &battery_details.model]).style(self.colours.text_style),
                 ];
 
                 // Draw
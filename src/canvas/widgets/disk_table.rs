@@ -18,7 +18,9 @@ use crate::{
 };
 use unicode_segmentation::UnicodeSegmentation;
 
-const DISK_HEADERS: [&str; 7] = ["Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s"];
+const DISK_HEADERS: [&str; 8] = [
+    "Disk", "Mount", "Used", "Free", "Total", "Full In", "R/s", "W/s",
+];
 
 static DISK_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
     DISK_HEADERS
@@ -63,10 +65,25 @@ impl DiskTableWidget for Painter {
                     .current_scroll_position
                     .saturating_sub(start_position),
             ));
-            let sliced_vec = &app_state.canvas_data.disk_data[start_position..];
+            let disk_data = app_state
+                .canvas_data
+                .disk_data
+                .get(&widget_id)
+                .map(|disk_data| disk_data.as_slice())
+                .unwrap_or_default();
+            let sliced_vec = disk_data.get(start_position..).unwrap_or_default();
 
             // Calculate widths
-            let hard_widths = [None, None, Some(4), Some(6), Some(6), Some(7), Some(7)];
+            let hard_widths = [
+                None,
+                None,
+                Some(4),
+                Some(6),
+                Some(6),
+                Some(7),
+                Some(7),
+                Some(7),
+            ];
             if recalculate_column_widths {
                 disk_widget_state.table_width_state.desired_column_widths = {
                     let mut column_widths = DISK_HEADERS_LENS.clone();
@@ -104,7 +121,7 @@ impl DiskTableWidget for Painter {
                         .iter()
                         .map(|w| Some(*w))
                         .collect::<Vec<_>>()),
-                    &[Some(0.2), Some(0.2), None, None, None, None, None],
+                    &[Some(0.2), Some(0.2), None, None, None, None, None, None],
                     &(disk_widget_state
                         .table_width_state
                         .desired_column_widths
@@ -117,8 +134,14 @@ impl DiskTableWidget for Painter {
 
             let dcw = &disk_widget_state.table_width_state.desired_column_widths;
             let ccw = &disk_widget_state.table_width_state.calculated_column_widths;
+            let disk_full_warnings = app_state
+                .canvas_data
+                .disk_full_warnings
+                .get(&widget_id)
+                .map(|disk_full_warnings| disk_full_warnings.as_slice())
+                .unwrap_or_default();
             let disk_rows =
-                sliced_vec.iter().map(|disk_row| {
+                sliced_vec.iter().enumerate().map(|(row_itx, disk_row)| {
                     let truncated_data = disk_row.iter().zip(&hard_widths).enumerate().map(
                         |(itx, (entry, width))| {
                             if width.is_none() {
@@ -151,7 +174,15 @@ impl DiskTableWidget for Painter {
                         },
                     );
 
-                    Row::new(truncated_data)
+                    if disk_full_warnings
+                        .get(start_position + row_itx)
+                        .copied()
+                        .unwrap_or(false)
+                    {
+                        Row::new(truncated_data).style(self.colours.disk_full_warning_style)
+                    } else {
+                        Row::new(truncated_data)
+                    }
                 });
 
             let (border_style, highlight_style) = if is_on_widget {
@@ -170,7 +201,7 @@ impl DiskTableWidget for Painter {
                         .scroll_state
                         .current_scroll_position
                         .saturating_add(1),
-                    app_state.canvas_data.disk_data.len()
+                    disk_data.len()
                 );
 
                 if title_string.len() <= draw_loc.width.into() {
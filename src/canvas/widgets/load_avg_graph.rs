@@ -0,0 +1,216 @@
+use crate::{
+    app::App,
+    canvas::{drawing_utils::interpolate_points, Painter},
+    constants::*,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    symbols::Marker,
+    terminal::Frame,
+    text::Span,
+    text::Spans,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const LOAD_AVG_NAMES: [&str; 3] = ["1m", "5m", "15m"];
+
+pub trait LoadAvgGraphWidget {
+    fn draw_load_avg_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl LoadAvgGraphWidget for Painter {
+    fn draw_load_avg_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(load_avg_widget_state) =
+            app_state.load_avg_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(load_avg_widget_state.current_display_time as f64);
+
+            let display_time_labels = vec![
+                Span::styled(
+                    format!("{}s", load_avg_widget_state.current_display_time / 1000),
+                    self.colours.graph_style,
+                ),
+                Span::styled("0s".to_string(), self.colours.graph_style),
+            ];
+
+            let x_axis = if app_state.app_config_fields.hide_time
+                || (app_state.app_config_fields.autohide_time
+                    && load_avg_widget_state.autohide_timer.is_none())
+            {
+                Axis::default().bounds([time_start, 0.0])
+            } else if let Some(time) = load_avg_widget_state.autohide_timer {
+                if std::time::Instant::now().duration_since(time).as_millis()
+                    < AUTOHIDE_TIMEOUT_MILLISECONDS.into()
+                {
+                    Axis::default()
+                        .bounds([time_start, 0.0])
+                        .style(self.colours.graph_style)
+                        .labels(display_time_labels)
+                } else {
+                    load_avg_widget_state.autohide_timer = None;
+                    Axis::default().bounds([time_start, 0.0])
+                }
+            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
+                Axis::default().bounds([time_start, 0.0])
+            } else {
+                Axis::default()
+                    .bounds([time_start, 0.0])
+                    .style(self.colours.graph_style)
+                    .labels(display_time_labels)
+            };
+
+            let load_avg_points = &mut app_state.canvas_data.load_avg_points;
+
+            // Interpolate values to avoid ugly gaps
+            let interpolated_points: Vec<Option<(usize, (f64, f64))>> = load_avg_points
+                .iter_mut()
+                .map(|data| {
+                    let end_pos = data.iter().position(|(time, _data)| *time >= time_start)?;
+                    if end_pos > 1 {
+                        let start_pos = end_pos - 1;
+                        let outside_point = *data.get(start_pos)?;
+                        let inside_point = *data.get(end_pos)?;
+                        let new_point = (
+                            time_start,
+                            interpolate_points(&outside_point, &inside_point, time_start),
+                        );
+
+                        *data.get_mut(start_pos)? = new_point;
+                        Some((start_pos, outside_point))
+                    } else {
+                        None // Point is already "leftmost", no need to interpolate.
+                    }
+                })
+                .collect();
+
+            // Scale to the largest visible value, but never below 1.0.
+            let y_max = load_avg_points
+                .iter()
+                .flat_map(|data| data.iter())
+                .filter(|(time, _data)| *time >= time_start)
+                .map(|(_time, data)| *data)
+                .fold(1.0_f64, f64::max)
+                .ceil();
+
+            let y_axis = Axis::default()
+                .style(self.colours.graph_style)
+                .bounds([0.0, y_max])
+                .labels(vec![
+                    Span::styled("0", self.colours.graph_style),
+                    Span::styled(format!("{}", y_max), self.colours.graph_style),
+                ]);
+
+            let load_avg_data = app_state.canvas_data.load_avg_data;
+            let divisor = if app_state.app_config_fields.normalize_load_avg {
+                app_state
+                    .data_collection
+                    .cpu_harvest
+                    .iter()
+                    .filter(|cpu| cpu.cpu_count.is_some())
+                    .count()
+                    .max(1) as f32
+            } else {
+                1.0
+            };
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+            let load_avg_datasets: Vec<Dataset<'_>> = load_avg_points
+                .iter()
+                .zip(LOAD_AVG_NAMES.iter())
+                .zip(load_avg_data.iter())
+                .enumerate()
+                .map(|(itx, ((data, name), current))| {
+                    Dataset::default()
+                        .name(format!("{}:{:.2}", name, current / divisor))
+                        .marker(marker)
+                        .style(
+                            self.colours.cpu_colour_styles
+                                [itx % self.colours.cpu_colour_styles.len()],
+                        )
+                        .data(data)
+                        .graph_type(tui::widgets::GraphType::Line)
+                })
+                .collect();
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let border_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            let title_text = if app_state.app_config_fields.normalize_load_avg {
+                " Load Average (per core) "
+            } else {
+                " Load Average "
+            };
+
+            let title = if app_state.is_expanded {
+                let title_base = format!("{}── Esc to go back ", title_text);
+                Spans::from(vec![
+                    Span::styled(title_text, self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                                UnicodeSegmentation::graphemes(title_base.as_str(), true).count()
+                                    + 2
+                            ))
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(Span::styled(title_text, self.colours.widget_title_style))
+            };
+
+            f.render_widget(
+                Chart::new(load_avg_datasets)
+                    .block(
+                        Block::default()
+                            .title(title)
+                            .borders(Borders::ALL)
+                            .border_style(border_style),
+                    )
+                    .x_axis(x_axis)
+                    .y_axis(y_axis)
+                    .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                draw_loc,
+            );
+
+            // Now if you're done, reset any interpolated points!
+            for (data, interpolated_point) in app_state
+                .canvas_data
+                .load_avg_points
+                .iter_mut()
+                .zip(interpolated_points)
+            {
+                if let Some((index, old_value)) = interpolated_point {
+                    if let Some(to_replace) = data.get_mut(index) {
+                        *to_replace = old_value;
+                    }
+                }
+            }
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
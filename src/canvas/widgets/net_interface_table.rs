@@ -0,0 +1,304 @@
+use once_cell::sync::Lazy;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::Span,
+    text::{Spans, Text},
+    widgets::{Block, Borders, Row, Table},
+};
+
+use crate::{
+    app,
+    canvas::{
+        drawing_utils::{get_column_widths, get_start_position},
+        Painter,
+    },
+    constants::*,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const NET_INTERFACE_HEADERS: [&str; 9] = [
+    "Interface",
+    "State",
+    "RX",
+    "TX",
+    "Wi-Fi",
+    "MTU",
+    "Speed",
+    "MAC",
+    "Addresses",
+];
+
+static NET_INTERFACE_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
+    NET_INTERFACE_HEADERS
+        .iter()
+        .map(|entry| entry.len() as u16)
+        .collect::<Vec<_>>()
+});
+
+pub trait NetInterfaceTableWidget {
+    fn draw_net_interface_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    );
+}
+
+impl NetInterfaceTableWidget for Painter {
+    fn draw_net_interface_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(net_interfaces_widget_state) = app_state
+            .net_interfaces_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
+                0
+            } else {
+                app_state.app_config_fields.table_gap
+            };
+            let start_position = get_start_position(
+                usize::from(
+                    (draw_loc.height + (1 - table_gap)).saturating_sub(self.table_height_offset),
+                ),
+                &net_interfaces_widget_state.scroll_state.scroll_direction,
+                &mut net_interfaces_widget_state
+                    .scroll_state
+                    .previous_scroll_position,
+                net_interfaces_widget_state
+                    .scroll_state
+                    .current_scroll_position,
+                app_state.is_force_redraw,
+            );
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let net_interfaces_table_state =
+                &mut net_interfaces_widget_state.scroll_state.table_state;
+            net_interfaces_table_state.select(Some(
+                net_interfaces_widget_state
+                    .scroll_state
+                    .current_scroll_position
+                    .saturating_sub(start_position),
+            ));
+            let sliced_vec = &app_state.canvas_data.interface_data[start_position..];
+
+            // Calculate widths
+            let hard_widths = [None; 9];
+            if recalculate_column_widths {
+                net_interfaces_widget_state
+                    .table_width_state
+                    .desired_column_widths = {
+                    let mut column_widths = NET_INTERFACE_HEADERS_LENS.clone();
+                    for row in sliced_vec {
+                        for (col, entry) in row.iter().enumerate() {
+                            if entry.len() as u16 > column_widths[col] {
+                                column_widths[col] = entry.len() as u16;
+                            }
+                        }
+                    }
+
+                    column_widths
+                };
+                net_interfaces_widget_state
+                    .table_width_state
+                    .calculated_column_widths = get_column_widths(
+                    draw_loc.width,
+                    &hard_widths,
+                    &(NET_INTERFACE_HEADERS_LENS
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>()),
+                    &[
+                        Some(0.2),
+                        Some(0.1),
+                        Some(0.12),
+                        Some(0.12),
+                        Some(0.15),
+                        Some(0.08),
+                        Some(0.15),
+                        Some(0.2),
+                        Some(-1.0),
+                    ],
+                    &net_interfaces_widget_state
+                        .table_width_state
+                        .desired_column_widths
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>(),
+                    true,
+                );
+            }
+
+            let dcw = &net_interfaces_widget_state
+                .table_width_state
+                .desired_column_widths;
+            let ccw = &net_interfaces_widget_state
+                .table_width_state
+                .calculated_column_widths;
+            let interface_rows = sliced_vec.iter().map(|interface_row| {
+                let truncated_data = interface_row.iter().zip(&hard_widths).enumerate().map(
+                    |(itx, (entry, width))| {
+                        if width.is_none() {
+                            if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                (dcw.get(itx), ccw.get(itx))
+                            {
+                                if *desired_col_width > *calculated_col_width
+                                    && *calculated_col_width > 0
+                                {
+                                    let calculated_col_width: usize =
+                                        (*calculated_col_width).into();
+
+                                    let graphemes =
+                                        UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                            .collect::<Vec<&str>>();
+
+                                    if graphemes.len() > calculated_col_width
+                                        && calculated_col_width > 1
+                                    {
+                                        // Truncate with ellipsis
+                                        let first_n =
+                                            graphemes[..(calculated_col_width - 1)].concat();
+                                        Text::raw(format!("{}…", first_n))
+                                    } else {
+                                        Text::raw(entry)
+                                    }
+                                } else {
+                                    Text::raw(entry)
+                                }
+                            } else {
+                                Text::raw(entry)
+                            }
+                        } else {
+                            Text::raw(entry)
+                        }
+                    },
+                );
+
+                Row::new(truncated_data)
+            });
+
+            let (border_style, highlight_style) = if is_on_widget {
+                (
+                    self.colours.highlighted_border_style,
+                    self.colours.currently_selected_text_style,
+                )
+            } else {
+                (self.colours.border_style, self.colours.text_style)
+            };
+
+            let title_base = if app_state.app_config_fields.show_table_scroll_position {
+                let title_string = format!(
+                    " Interfaces ({} of {}) ",
+                    net_interfaces_widget_state
+                        .scroll_state
+                        .current_scroll_position
+                        .saturating_add(1),
+                    app_state.canvas_data.interface_data.len()
+                );
+
+                if title_string.len() <= draw_loc.width.into() {
+                    title_string
+                } else {
+                    " Interfaces ".to_string()
+                }
+            } else {
+                " Interfaces ".to_string()
+            };
+
+            let title = if app_state.is_expanded {
+                const ESCAPE_ENDING: &str = "── Esc to go back ";
+
+                let (chosen_title_base, expanded_title_base) = {
+                    let interface_title_base = format!("{}{}", title_base, ESCAPE_ENDING);
+
+                    if interface_title_base.len() > draw_loc.width.into() {
+                        (
+                            " Interfaces ".to_string(),
+                            format!("{}{}", " Interfaces ", ESCAPE_ENDING),
+                        )
+                    } else {
+                        (title_base, interface_title_base)
+                    }
+                };
+
+                Spans::from(vec![
+                    Span::styled(chosen_title_base, self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(
+                                usize::from(draw_loc.width).saturating_sub(
+                                    UnicodeSegmentation::graphemes(
+                                        expanded_title_base.as_str(),
+                                        true
+                                    )
+                                    .count()
+                                        + 2
+                                )
+                            )
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(Span::styled(title_base, self.colours.widget_title_style))
+            };
+
+            let interface_block = if draw_border {
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+            } else if is_on_widget {
+                Block::default()
+                    .borders(SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style)
+            } else {
+                Block::default().borders(Borders::NONE)
+            };
+
+            let margined_draw_loc = Layout::default()
+                .constraints([Constraint::Percentage(100)])
+                .horizontal_margin(if is_on_widget || draw_border { 0 } else { 1 })
+                .direction(Direction::Horizontal)
+                .split(draw_loc)[0];
+
+            // Draw
+            f.render_stateful_widget(
+                Table::new(interface_rows)
+                    .header(
+                        Row::new(NET_INTERFACE_HEADERS.to_vec())
+                            .style(self.colours.table_header_style)
+                            .bottom_margin(table_gap),
+                    )
+                    .block(interface_block)
+                    .highlight_style(highlight_style)
+                    .style(self.colours.text_style)
+                    .widths(
+                        &(net_interfaces_widget_state
+                            .table_width_state
+                            .calculated_column_widths
+                            .iter()
+                            .map(|calculated_width| Constraint::Length(*calculated_width))
+                            .collect::<Vec<_>>()),
+                    ),
+                margined_draw_loc,
+                net_interfaces_table_state,
+            );
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                // Note there is no difference between this and using draw_loc, but I'm too lazy to fix it.
+                if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                    widget.top_left_corner = Some((margined_draw_loc.x, margined_draw_loc.y));
+                    widget.bottom_right_corner = Some((
+                        margined_draw_loc.x + margined_draw_loc.width,
+                        margined_draw_loc.y + margined_draw_loc.height,
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -23,7 +23,9 @@ use tui::{
     widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
 };
 
-const NETWORK_HEADERS: [&str; 4] = ["RX", "TX", "Total RX", "Total TX"];
+const NETWORK_HEADERS: [&str; 8] = [
+    "RX", "TX", "Total RX", "Total TX", "RX Pkt", "TX Pkt", "Errors", "Drops",
+];
 
 static NETWORK_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
     NETWORK_HEADERS
@@ -42,6 +44,10 @@ pub trait NetworkGraphWidget {
         hide_legend: bool,
     );
 
+    fn draw_network_packet_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+
     fn draw_network_labels<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     );
@@ -51,7 +57,7 @@ impl NetworkGraphWidget for Painter {
     fn draw_network<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
-        if app_state.app_config_fields.use_old_network_legend {
+        let (graph_loc, labels_loc) = if app_state.app_config_fields.use_old_network_legend {
             let network_chunk = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(0)
@@ -61,10 +67,32 @@ impl NetworkGraphWidget for Painter {
                 ])
                 .split(draw_loc);
 
-            self.draw_network_graph(f, app_state, network_chunk[0], widget_id, true);
-            self.draw_network_labels(f, app_state, network_chunk[1], widget_id);
+            (network_chunk[0], Some(network_chunk[1]))
+        } else {
+            (draw_loc, None)
+        };
+
+        if app_state.app_config_fields.network_packet_graph {
+            let graph_chunk = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(0)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(graph_loc);
+
+            self.draw_network_graph(
+                f,
+                app_state,
+                graph_chunk[0],
+                widget_id,
+                labels_loc.is_some(),
+            );
+            self.draw_network_packet_graph(f, app_state, graph_chunk[1], widget_id);
         } else {
-            self.draw_network_graph(f, app_state, draw_loc, widget_id, false);
+            self.draw_network_graph(f, app_state, graph_loc, widget_id, labels_loc.is_some());
+        }
+
+        if let Some(labels_loc) = labels_loc {
+            self.draw_network_labels(f, app_state, labels_loc, widget_id);
         }
 
         if app_state.should_get_widget_bounds() {
@@ -644,7 +672,7 @@ impl NetworkGraphWidget for Painter {
                         .style(self.colours.total_tx_style),
                 ]
             } else {
-                vec![
+                let mut dataset = vec![
                     Dataset::default()
                         .name(&app_state.canvas_data.rx_display)
                         .marker(if app_state.app_config_fields.use_dot {
@@ -665,7 +693,29 @@ impl NetworkGraphWidget for Painter {
                         .style(self.colours.tx_style)
                         .data(network_data_tx)
                         .graph_type(tui::widgets::GraphType::Line),
-                ]
+                    Dataset::default()
+                        .name(format!(
+                            "Pkt RX: {}  TX: {}",
+                            app_state.canvas_data.rx_packets_display,
+                            app_state.canvas_data.tx_packets_display
+                        ))
+                        .style(self.colours.total_rx_style),
+                    Dataset::default()
+                        .name(format!(
+                            "Err: {}  Drop: {}",
+                            app_state.canvas_data.errors_display,
+                            app_state.canvas_data.drops_display
+                        ))
+                        .style(self.colours.total_tx_style),
+                ];
+                dataset.extend(app_state.canvas_data.wireless_displays.iter().map(
+                    |wireless_display| {
+                        Dataset::default()
+                            .name(wireless_display.as_str())
+                            .style(self.colours.text_style)
+                    },
+                ));
+                dataset
             };
 
             f.render_widget(
@@ -701,6 +751,119 @@ impl NetworkGraphWidget for Painter {
         }
     }
 
+    fn draw_network_packet_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(network_widget_state) = app_state.net_state.widget_states.get(&widget_id) {
+            let time_start = -(network_widget_state.current_display_time as f64);
+            let x_axis = Axis::default().bounds([time_start, 0.0]);
+
+            let mut packet_data = [
+                &mut app_state.canvas_data.network_data_rx_packets,
+                &mut app_state.canvas_data.network_data_tx_packets,
+            ];
+
+            // Interpolate values to avoid ugly gaps
+            let interpolated_points: Vec<Option<(usize, (f64, f64))>> = packet_data
+                .iter_mut()
+                .map(|data| {
+                    let end_pos = data.iter().position(|(time, _data)| *time >= time_start)?;
+                    if end_pos > 1 {
+                        let start_pos = end_pos - 1;
+                        let outside_point = *data.get(start_pos)?;
+                        let inside_point = *data.get(end_pos)?;
+                        let new_point = (
+                            time_start,
+                            interpolate_points(&outside_point, &inside_point, time_start),
+                        );
+
+                        *data.get_mut(start_pos)? = new_point;
+                        Some((start_pos, outside_point))
+                    } else {
+                        None // Point is already "leftmost", no need to interpolate.
+                    }
+                })
+                .collect();
+
+            let rx_packets = &app_state.canvas_data.network_data_rx_packets;
+            let tx_packets = &app_state.canvas_data.network_data_tx_packets;
+
+            // Scale to the largest visible value, with a bit of headroom.
+            let max_entry = rx_packets
+                .iter()
+                .chain(tx_packets.iter())
+                .filter(|(time, _data)| *time >= time_start)
+                .map(|(_time, data)| *data)
+                .fold(0.0_f64, f64::max);
+            let max_range = (max_entry * 1.1).max(10.0).ceil();
+            let (converted_max, prefix) = get_decimal_prefix(max_range as u64, "");
+
+            let y_axis = Axis::default()
+                .style(self.colours.graph_style)
+                .bounds([0.0, max_range])
+                .labels(vec![
+                    Span::styled("0", self.colours.graph_style),
+                    Span::styled(
+                        format!("{:.0}{}", converted_max, prefix),
+                        self.colours.graph_style,
+                    ),
+                ]);
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+            let dataset = vec![
+                Dataset::default()
+                    .name(format!("RX: {}", app_state.canvas_data.rx_packets_display))
+                    .marker(marker)
+                    .style(self.colours.rx_style)
+                    .data(rx_packets)
+                    .graph_type(tui::widgets::GraphType::Line),
+                Dataset::default()
+                    .name(format!("TX: {}", app_state.canvas_data.tx_packets_display))
+                    .marker(marker)
+                    .style(self.colours.tx_style)
+                    .data(tx_packets)
+                    .graph_type(tui::widgets::GraphType::Line),
+            ];
+
+            let border_style = if app_state.current_widget.widget_id == widget_id {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            f.render_widget(
+                Chart::new(dataset)
+                    .block(
+                        Block::default()
+                            .title(Span::styled(" Packets ", self.colours.widget_title_style))
+                            .borders(Borders::ALL)
+                            .border_style(border_style),
+                    )
+                    .x_axis(x_axis)
+                    .y_axis(y_axis)
+                    .hidden_legend_constraints((Constraint::Ratio(1, 1), Constraint::Ratio(3, 4))),
+                draw_loc,
+            );
+
+            // Now if you're done, reset any interpolated points!
+            let mut packet_data = [
+                &mut app_state.canvas_data.network_data_rx_packets,
+                &mut app_state.canvas_data.network_data_tx_packets,
+            ];
+            for (data, interpolated_point) in packet_data.iter_mut().zip(interpolated_points) {
+                if let Some((index, old_value)) = interpolated_point {
+                    if let Some(to_replace) = data.get_mut(index) {
+                        *to_replace = old_value;
+                    }
+                }
+            }
+        }
+    }
+
     fn draw_network_labels<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
@@ -714,6 +877,10 @@ impl NetworkGraphWidget for Painter {
         let tx_display = &app_state.canvas_data.tx_display;
         let total_rx_display = &app_state.canvas_data.total_rx_display;
         let total_tx_display = &app_state.canvas_data.total_tx_display;
+        let rx_packets_display = &app_state.canvas_data.rx_packets_display;
+        let tx_packets_display = &app_state.canvas_data.tx_packets_display;
+        let errors_display = &app_state.canvas_data.errors_display;
+        let drops_display = &app_state.canvas_data.drops_display;
 
         // Gross but I need it to work...
         let total_network = vec![vec![
@@ -721,6 +888,10 @@ impl NetworkGraphWidget for Painter {
             Text::raw(tx_display),
             Text::raw(total_rx_display),
             Text::raw(total_tx_display),
+            Text::raw(rx_packets_display),
+            Text::raw(tx_packets_display),
+            Text::raw(errors_display),
+            Text::raw(drops_display),
         ]];
         let mapped_network = total_network
             .into_iter()
@@ -729,12 +900,12 @@ impl NetworkGraphWidget for Painter {
         // Calculate widths
         let intrinsic_widths = get_column_widths(
             draw_loc.width,
-            &[None, None, None, None],
+            &[None; 8],
             &(NETWORK_HEADERS_LENS
                 .iter()
                 .map(|s| Some(*s))
                 .collect::<Vec<_>>()),
-            &[Some(0.25); 4],
+            &[Some(0.125); 8],
             &(NETWORK_HEADERS_LENS
                 .iter()
                 .map(|s| Some(*s))
@@ -1,18 +1,22 @@
 use crate::{
-    app::App,
+    app::{data_farmer::PROCESS_IO_HISTORY_WINDOW, App},
     canvas::{
         drawing_utils::{get_column_widths, get_search_start_position, get_start_position},
         Painter,
     },
     constants::*,
+    data_conversion::convert_process_io_data_points,
+    data_harvester::processes::ProcessSorting,
+    utils::gen_util::get_decimal_bytes,
 };
 
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    symbols::Marker,
     terminal::Frame,
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    widgets::{Axis, Block, Borders, Cell, Chart, Dataset, Paragraph, Row, Table},
 };
 
 use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
@@ -87,6 +91,34 @@ const PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_ELSE: &[Option<f64>] = &[
     Some(0.2),
 ];
 
+/// How full a process' file descriptor table has to be, as a percentage of its soft limit, before
+/// its file descriptor columns are highlighted.
+const FD_WARNING_PERCENT: f64 = 80.0;
+
+/// Returns the hard width of an optional process column.
+fn get_optional_column_width(column: &ProcessSorting) -> Option<u16> {
+    match column {
+        ProcessSorting::ReadChars
+        | ProcessSorting::WriteChars
+        | ProcessSorting::ReadSyscalls
+        | ProcessSorting::WriteSyscalls
+        | ProcessSorting::CancelledWrite
+        | ProcessSorting::Affinity => Some(8),
+        ProcessSorting::LastCpu => Some(5),
+        ProcessSorting::OomScore | ProcessSorting::OomScoreAdj => Some(7),
+        ProcessSorting::Unit => Some(8),
+        ProcessSorting::MinorFaults
+        | ProcessSorting::MajorFaults
+        | ProcessSorting::VoluntarySwitches
+        | ProcessSorting::InvoluntarySwitches => Some(9),
+        ProcessSorting::FdCount | ProcessSorting::FdPercent => Some(6),
+        ProcessSorting::DeletedCode => Some(8),
+        ProcessSorting::Tty => Some(7),
+        ProcessSorting::SessionId | ProcessSorting::ProcessGroupId => Some(8),
+        _ => None,
+    }
+}
+
 pub trait ProcessTableWidget {
     /// Draws and handles all process-related drawing.  Use this.
     /// - `widget_id` here represents the widget ID of the process widget itself!
@@ -104,6 +136,14 @@ pub trait ProcessTableWidget {
         widget_id: u64,
     );
 
+    /// Draws the read and write rates of the selected process.
+    /// - `widget_id` represents the widget ID of the process widget itself.
+    ///
+    /// This should not be directly called.
+    fn draw_process_io_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+
     /// Draws the process search field.
     /// - `widget_id` represents the widget ID of the search box itself --- NOT the process widget
     /// state that is stored.
@@ -133,6 +173,7 @@ impl ProcessTableWidget for Painter {
         if let Some(process_widget_state) = app_state.proc_state.widget_states.get(&widget_id) {
             let search_height = if draw_border { 5 } else { 3 };
             let is_sort_open = process_widget_state.is_sort_open;
+            let is_io_graph_shown = process_widget_state.is_io_graph_shown;
             let header_len = process_widget_state.columns.longest_header_len;
 
             let mut proc_draw_loc = draw_loc;
@@ -168,10 +209,143 @@ impl ProcessTableWidget for Painter {
                 );
             }
 
+            if is_io_graph_shown {
+                let processes_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                    .split(proc_draw_loc);
+                proc_draw_loc = processes_chunk[0];
+
+                self.draw_process_io_graph(f, app_state, processes_chunk[1], widget_id);
+            }
+
             self.draw_processes_table(f, app_state, proc_draw_loc, draw_border, widget_id);
         }
     }
 
+    fn draw_process_io_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let selected_process =
+            app_state
+                .proc_state
+                .widget_states
+                .get(&widget_id)
+                .and_then(|proc_widget_state| {
+                    app_state
+                        .canvas_data
+                        .finalized_process_data_map
+                        .get(&widget_id)
+                        .and_then(|finalized_process_data| {
+                            finalized_process_data
+                                .get(proc_widget_state.scroll_state.current_scroll_position)
+                        })
+                        .map(|process| {
+                            let title = if proc_widget_state.is_grouped {
+                                format!(" I/O: {} ", process.name)
+                            } else if proc_widget_state.is_using_command {
+                                format!(" I/O: {} ({}) ", process.command, process.pid)
+                            } else {
+                                format!(" I/O: {} ({}) ", process.name, process.pid)
+                            };
+                            (title, process.group_pids.clone())
+                        })
+                });
+
+        let (title, (read_points, write_points)) = match selected_process {
+            Some((title, pids)) => (
+                title,
+                convert_process_io_data_points(
+                    &app_state.data_collection,
+                    &pids,
+                    app_state.is_frozen,
+                ),
+            ),
+            None => (" I/O ".to_string(), (vec![], vec![])),
+        };
+
+        let time_start = -(PROCESS_IO_HISTORY_WINDOW.as_millis() as f64);
+        let x_axis =
+            if app_state.app_config_fields.hide_time || draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
+                Axis::default().bounds([time_start, 0.0])
+            } else {
+                Axis::default()
+                    .bounds([time_start, 0.0])
+                    .style(self.colours.graph_style)
+                    .labels(vec![
+                        Span::styled(
+                            format!("{}s", PROCESS_IO_HISTORY_WINDOW.as_secs()),
+                            self.colours.graph_style,
+                        ),
+                        Span::styled("0s".to_string(), self.colours.graph_style),
+                    ])
+            };
+
+        let get_rate_string = |rate: f64| {
+            let converted_rate = get_decimal_bytes(rate as u64);
+            format!("{:.0}{}/s", converted_rate.0, converted_rate.1)
+        };
+
+        // Scale to the largest visible value, but never below 1KB/s.
+        let y_max = read_points
+            .iter()
+            .chain(write_points.iter())
+            .map(|(_time, rate)| *rate)
+            .fold(1000.0_f64, f64::max);
+        let y_axis = Axis::default()
+            .style(self.colours.graph_style)
+            .bounds([0.0, y_max])
+            .labels(vec![
+                Span::styled("0B/s", self.colours.graph_style),
+                Span::styled(get_rate_string(y_max), self.colours.graph_style),
+            ]);
+
+        let current_read = read_points.last().map(|(_time, rate)| *rate).unwrap_or(0.0);
+        let current_write = write_points
+            .last()
+            .map(|(_time, rate)| *rate)
+            .unwrap_or(0.0);
+
+        let marker = if app_state.app_config_fields.use_dot {
+            Marker::Dot
+        } else {
+            Marker::Braille
+        };
+
+        let border_style = if widget_id == app_state.current_widget.widget_id {
+            self.colours.highlighted_border_style
+        } else {
+            self.colours.border_style
+        };
+
+        f.render_widget(
+            Chart::new(vec![
+                Dataset::default()
+                    .name(format!("R: {}", get_rate_string(current_read)))
+                    .marker(marker)
+                    .style(self.colours.rx_style)
+                    .data(&read_points)
+                    .graph_type(tui::widgets::GraphType::Line),
+                Dataset::default()
+                    .name(format!("W: {}", get_rate_string(current_write)))
+                    .marker(marker)
+                    .style(self.colours.tx_style)
+                    .data(&write_points)
+                    .graph_type(tui::widgets::GraphType::Line),
+            ])
+            .block(
+                Block::default()
+                    .title(Span::styled(title, self.colours.widget_title_style))
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            )
+            .x_axis(x_axis)
+            .y_axis(y_axis)
+            .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            draw_loc,
+        );
+    }
+
     fn draw_processes_table<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, draw_border: bool,
         widget_id: u64,
@@ -227,6 +401,44 @@ impl ProcessTableWidget for Painter {
                 " Processes ".to_string()
             };
 
+            // Zombie, uninterruptible, and stopped processes are counted across every process, not
+            // just the filtered ones, as a pile-up of any of them is worth knowing about.
+            let (zombie_count, uninterruptible_count, stopped_count) =
+                app_state.canvas_data.single_process_data.values().fold(
+                    (0, 0, 0),
+                    |(zombie_count, uninterruptible_count, stopped_count), process| match process
+                        .process_char
+                    {
+                        'Z' => (zombie_count + 1, uninterruptible_count, stopped_count),
+                        'D' => (zombie_count, uninterruptible_count + 1, stopped_count),
+                        'T' => (zombie_count, uninterruptible_count, stopped_count + 1),
+                        _ => (zombie_count, uninterruptible_count, stopped_count),
+                    },
+                );
+            let mut state_count_spans = vec![];
+            if zombie_count > 0 {
+                state_count_spans.push(Span::styled(
+                    format!("Z: {} ", zombie_count),
+                    self.colours.zombie_process_style,
+                ));
+            }
+            if uninterruptible_count > 0 {
+                state_count_spans.push(Span::styled(
+                    format!("D: {} ", uninterruptible_count),
+                    self.colours.uninterruptible_process_style,
+                ));
+            }
+            if stopped_count > 0 {
+                state_count_spans.push(Span::styled(
+                    format!("T: {} ", stopped_count),
+                    self.colours.stopped_process_style,
+                ));
+            }
+            let state_count_len: usize = state_count_spans
+                .iter()
+                .map(|span| span.content.len())
+                .sum();
+
             let title = if app_state.is_expanded
                 && !proc_widget_state
                     .process_search_state
@@ -249,27 +461,31 @@ impl ProcessTableWidget for Painter {
                     }
                 };
 
-                Spans::from(vec![
-                    Span::styled(chosen_title_base, self.colours.widget_title_style),
-                    Span::styled(
-                        format!(
-                            "─{}─ Esc to go back ",
-                            "─".repeat(
-                                usize::from(draw_loc.width).saturating_sub(
-                                    UnicodeSegmentation::graphemes(
-                                        expanded_title_base.as_str(),
-                                        true
-                                    )
+                let mut title_spans = vec![Span::styled(
+                    chosen_title_base,
+                    self.colours.widget_title_style,
+                )];
+                title_spans.extend(state_count_spans);
+                title_spans.push(Span::styled(
+                    format!(
+                        "─{}─ Esc to go back ",
+                        "─".repeat(
+                            usize::from(draw_loc.width).saturating_sub(
+                                UnicodeSegmentation::graphemes(expanded_title_base.as_str(), true)
                                     .count()
-                                        + 2
-                                )
+                                    + state_count_len
+                                    + 2
                             )
-                        ),
-                        border_style,
+                        )
                     ),
-                ])
+                    border_style,
+                ));
+                Spans::from(title_spans)
             } else {
-                Spans::from(Span::styled(title_base, self.colours.widget_title_style))
+                let mut title_spans =
+                    vec![Span::styled(title_base, self.colours.widget_title_style)];
+                title_spans.extend(state_count_spans);
+                Spans::from(title_spans)
             };
 
             let process_block = if draw_border {
@@ -339,11 +555,20 @@ impl ProcessTableWidget for Painter {
 
                 // Calculate widths
                 // FIXME: See if we can move this into the recalculate block?  I want to move column widths into the column widths
+                let optional_columns = proc_widget_state.columns.get_enabled_optional_columns();
                 let hard_widths = if proc_widget_state.is_grouped {
                     PROCESS_HEADERS_HARD_WIDTH_GROUPED
                 } else {
                     PROCESS_HEADERS_HARD_WIDTH_NO_GROUP
-                };
+                }
+                .iter()
+                .cloned()
+                .chain(
+                    optional_columns
+                        .iter()
+                        .map(|column| get_optional_column_width(column)),
+                )
+                .collect::<Vec<_>>();
 
                 if recalculate_column_widths {
                     let mut column_widths = process_headers
@@ -374,7 +599,7 @@ impl ProcessTableWidget for Painter {
                         .table_width_state
                         .desired_column_widths
                         .iter()
-                        .zip(hard_widths)
+                        .zip(&hard_widths)
                         .map(|(current, hard)| {
                             if let Some(hard) = hard {
                                 if *hard > *current {
@@ -388,7 +613,7 @@ impl ProcessTableWidget for Painter {
                         })
                         .collect::<Vec<_>>();
 
-                    let soft_widths_max = if proc_widget_state.is_grouped {
+                    let soft_widths_max: Vec<Option<f64>> = if proc_widget_state.is_grouped {
                         // Note grouped trees are not a thing.
 
                         if proc_widget_state.is_using_command {
@@ -402,14 +627,18 @@ impl ProcessTableWidget for Painter {
                         PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_TREE
                     } else {
                         PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_ELSE
-                    };
+                    }
+                    .iter()
+                    .cloned()
+                    .chain(optional_columns.iter().map(|_column| None))
+                    .collect();
 
                     proc_widget_state.table_width_state.calculated_column_widths =
                         get_column_widths(
                             draw_loc.width,
-                            hard_widths,
+                            &hard_widths,
                             &soft_widths_min,
-                            soft_widths_max,
+                            &soft_widths_max,
                             &(proc_widget_state
                                 .table_width_state
                                 .desired_column_widths
@@ -432,53 +661,97 @@ impl ProcessTableWidget for Painter {
                 let dcw = &proc_widget_state.table_width_state.desired_column_widths;
                 let ccw = &proc_widget_state.table_width_state.calculated_column_widths;
 
-                let process_rows = sliced_vec.iter().map(|(data, disabled)| {
-                    let truncated_data = data.iter().zip(hard_widths).enumerate().map(
-                        |(itx, ((entry, alternative), width))| {
-                            if let (Some(desired_col_width), Some(calculated_col_width)) =
-                                (dcw.get(itx), ccw.get(itx))
-                            {
-                                if width.is_none() {
-                                    if *desired_col_width > *calculated_col_width
-                                        && *calculated_col_width > 0
+                let finalized_process_data = app_state
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id);
+                let base_column_count = hard_widths.len() - optional_columns.len();
+                let fd_column_indices = optional_columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_itx, column)| {
+                        matches!(column, ProcessSorting::FdCount | ProcessSorting::FdPercent)
+                    })
+                    .map(|(itx, _column)| base_column_count + itx)
+                    .collect::<Vec<_>>();
+                let process_rows =
+                    sliced_vec
+                        .iter()
+                        .enumerate()
+                        .map(|(row_index, (data, disabled))| {
+                            let truncated_data = data.iter().zip(&hard_widths).enumerate().map(
+                                |(itx, ((entry, alternative), width))| {
+                                    if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                        (dcw.get(itx), ccw.get(itx))
                                     {
-                                        let calculated_col_width: usize =
-                                            (*calculated_col_width).into();
-
-                                        let graphemes =
-                                            UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                        if width.is_none() {
+                                            if *desired_col_width > *calculated_col_width
+                                                && *calculated_col_width > 0
+                                            {
+                                                let calculated_col_width: usize =
+                                                    (*calculated_col_width).into();
+
+                                                let graphemes = UnicodeSegmentation::graphemes(
+                                                    entry.as_str(),
+                                                    true,
+                                                )
                                                 .collect::<Vec<&str>>();
 
-                                        if let Some(alternative) = alternative {
-                                            Text::raw(alternative)
-                                        } else if graphemes.len() > calculated_col_width
-                                            && calculated_col_width > 1
-                                        {
-                                            // Truncate with ellipsis
-                                            let first_n =
-                                                graphemes[..(calculated_col_width - 1)].concat();
-                                            Text::raw(format!("{}…", first_n))
+                                                if let Some(alternative) = alternative {
+                                                    Text::raw(alternative)
+                                                } else if graphemes.len() > calculated_col_width
+                                                    && calculated_col_width > 1
+                                                {
+                                                    // Truncate with ellipsis
+                                                    let first_n = graphemes
+                                                        [..(calculated_col_width - 1)]
+                                                        .concat();
+                                                    Text::raw(format!("{}…", first_n))
+                                                } else {
+                                                    Text::raw(entry)
+                                                }
+                                            } else {
+                                                Text::raw(entry)
+                                            }
                                         } else {
                                             Text::raw(entry)
                                         }
                                     } else {
                                         Text::raw(entry)
                                     }
+                                },
+                            );
+
+                            let process = finalized_process_data.and_then(|process_data| {
+                                process_data.get(start_position + row_index)
+                            });
+                            let process_char = process.map(|process| process.process_char);
+                            let is_near_fd_limit = !*disabled
+                                && process
+                                    .and_then(|process| process.fd_percent)
+                                    .map_or(false, |fd_percent| fd_percent >= FD_WARNING_PERCENT);
+                            let truncated_data = truncated_data.enumerate().map(|(itx, entry)| {
+                                if is_near_fd_limit && fd_column_indices.contains(&itx) {
+                                    Cell::from(entry).style(self.colours.fd_warning_style)
                                 } else {
-                                    Text::raw(entry)
+                                    Cell::from(entry)
                                 }
+                            });
+
+                            if *disabled {
+                                Row::new(truncated_data).style(self.colours.disabled_text_style)
                             } else {
-                                Text::raw(entry)
+                                match process_char {
+                                    Some('Z') => Row::new(truncated_data)
+                                        .style(self.colours.zombie_process_style),
+                                    Some('D') => Row::new(truncated_data)
+                                        .style(self.colours.uninterruptible_process_style),
+                                    Some('T') => Row::new(truncated_data)
+                                        .style(self.colours.stopped_process_style),
+                                    _ => Row::new(truncated_data),
+                                }
                             }
-                        },
-                    );
-
-                    if *disabled {
-                        Row::new(truncated_data).style(self.colours.disabled_text_style)
-                    } else {
-                        Row::new(truncated_data)
-                    }
-                });
+                        });
 
                 f.render_stateful_widget(
                     Table::new(process_rows)
@@ -0,0 +1,440 @@
+use once_cell::sync::Lazy;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    symbols::Marker,
+    terminal::Frame,
+    text::Span,
+    text::{Spans, Text},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
+};
+
+use crate::{
+    app,
+    canvas::{
+        drawing_utils::{get_column_widths, get_start_position, interpolate_points},
+        Painter,
+    },
+    constants::*,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const PROTOCOL_HEADERS: [&str; 3] = ["Statistic", "Rate", "Total"];
+
+static PROTOCOL_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
+    PROTOCOL_HEADERS
+        .iter()
+        .map(|entry| entry.len() as u16)
+        .collect::<Vec<_>>()
+});
+
+pub trait ProtocolTableWidget {
+    fn draw_protocols<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    );
+
+    fn draw_protocol_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    );
+
+    fn draw_tcp_retransmit_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl ProtocolTableWidget for Painter {
+    fn draw_protocols<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    ) {
+        let is_graph_shown = app_state
+            .protocols_state
+            .get_widget_state(widget_id)
+            .map(|protocols_widget_state| protocols_widget_state.is_graph_shown)
+            .unwrap_or(false);
+
+        if is_graph_shown {
+            let protocols_loc = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(draw_loc);
+
+            self.draw_protocol_table(f, app_state, protocols_loc[0], draw_border, widget_id);
+            self.draw_tcp_retransmit_graph(f, app_state, protocols_loc[1], widget_id);
+        } else {
+            self.draw_protocol_table(f, app_state, draw_loc, draw_border, widget_id);
+        }
+    }
+
+    fn draw_protocol_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(protocols_widget_state) =
+            app_state.protocols_state.widget_states.get_mut(&widget_id)
+        {
+            let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
+                0
+            } else {
+                app_state.app_config_fields.table_gap
+            };
+            let start_position = get_start_position(
+                usize::from(
+                    (draw_loc.height + (1 - table_gap)).saturating_sub(self.table_height_offset),
+                ),
+                &protocols_widget_state.scroll_state.scroll_direction,
+                &mut protocols_widget_state.scroll_state.previous_scroll_position,
+                protocols_widget_state.scroll_state.current_scroll_position,
+                app_state.is_force_redraw,
+            );
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let protocols_table_state = &mut protocols_widget_state.scroll_state.table_state;
+            protocols_table_state.select(Some(
+                protocols_widget_state
+                    .scroll_state
+                    .current_scroll_position
+                    .saturating_sub(start_position),
+            ));
+            let sliced_vec = &app_state.canvas_data.protocol_data[start_position..];
+
+            // Calculate widths
+            let hard_widths = [None; 3];
+            if recalculate_column_widths {
+                protocols_widget_state
+                    .table_width_state
+                    .desired_column_widths = {
+                    let mut column_widths = PROTOCOL_HEADERS_LENS.clone();
+                    for row in sliced_vec {
+                        for (col, entry) in row.iter().enumerate() {
+                            if entry.len() as u16 > column_widths[col] {
+                                column_widths[col] = entry.len() as u16;
+                            }
+                        }
+                    }
+
+                    column_widths
+                };
+                protocols_widget_state
+                    .table_width_state
+                    .calculated_column_widths = get_column_widths(
+                    draw_loc.width,
+                    &hard_widths,
+                    &(PROTOCOL_HEADERS_LENS
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>()),
+                    &[Some(0.5), Some(0.25), Some(-1.0)],
+                    &protocols_widget_state
+                        .table_width_state
+                        .desired_column_widths
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>(),
+                    true,
+                );
+            }
+
+            let dcw = &protocols_widget_state
+                .table_width_state
+                .desired_column_widths;
+            let ccw = &protocols_widget_state
+                .table_width_state
+                .calculated_column_widths;
+            let protocol_rows = sliced_vec.iter().map(|protocol_row| {
+                let truncated_data = protocol_row.iter().zip(&hard_widths).enumerate().map(
+                    |(itx, (entry, width))| {
+                        if width.is_none() {
+                            if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                (dcw.get(itx), ccw.get(itx))
+                            {
+                                if *desired_col_width > *calculated_col_width
+                                    && *calculated_col_width > 0
+                                {
+                                    let calculated_col_width: usize =
+                                        (*calculated_col_width).into();
+
+                                    let graphemes =
+                                        UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                            .collect::<Vec<&str>>();
+
+                                    if graphemes.len() > calculated_col_width
+                                        && calculated_col_width > 1
+                                    {
+                                        // Truncate with ellipsis
+                                        let first_n =
+                                            graphemes[..(calculated_col_width - 1)].concat();
+                                        Text::raw(format!("{}…", first_n))
+                                    } else {
+                                        Text::raw(entry)
+                                    }
+                                } else {
+                                    Text::raw(entry)
+                                }
+                            } else {
+                                Text::raw(entry)
+                            }
+                        } else {
+                            Text::raw(entry)
+                        }
+                    },
+                );
+
+                Row::new(truncated_data)
+            });
+
+            let (border_style, highlight_style) = if is_on_widget {
+                (
+                    self.colours.highlighted_border_style,
+                    self.colours.currently_selected_text_style,
+                )
+            } else {
+                (self.colours.border_style, self.colours.text_style)
+            };
+
+            let title_base = if app_state.app_config_fields.show_table_scroll_position {
+                let title_string = format!(
+                    " Protocols ({} of {}) ",
+                    protocols_widget_state
+                        .scroll_state
+                        .current_scroll_position
+                        .saturating_add(1),
+                    app_state.canvas_data.protocol_data.len()
+                );
+
+                if title_string.len() <= draw_loc.width.into() {
+                    title_string
+                } else {
+                    " Protocols ".to_string()
+                }
+            } else {
+                " Protocols ".to_string()
+            };
+
+            let title = if app_state.is_expanded {
+                const ESCAPE_ENDING: &str = "── Esc to go back ";
+
+                let (chosen_title_base, expanded_title_base) = {
+                    let protocol_title_base = format!("{}{}", title_base, ESCAPE_ENDING);
+
+                    if protocol_title_base.len() > draw_loc.width.into() {
+                        (
+                            " Protocols ".to_string(),
+                            format!("{}{}", " Protocols ", ESCAPE_ENDING),
+                        )
+                    } else {
+                        (title_base, protocol_title_base)
+                    }
+                };
+
+                Spans::from(vec![
+                    Span::styled(chosen_title_base, self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(
+                                usize::from(draw_loc.width).saturating_sub(
+                                    UnicodeSegmentation::graphemes(
+                                        expanded_title_base.as_str(),
+                                        true
+                                    )
+                                    .count()
+                                        + 2
+                                )
+                            )
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(Span::styled(title_base, self.colours.widget_title_style))
+            };
+
+            let protocol_block = if draw_border {
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+            } else if is_on_widget {
+                Block::default()
+                    .borders(SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style)
+            } else {
+                Block::default().borders(Borders::NONE)
+            };
+
+            let margined_draw_loc = Layout::default()
+                .constraints([Constraint::Percentage(100)])
+                .horizontal_margin(if is_on_widget || draw_border { 0 } else { 1 })
+                .direction(Direction::Horizontal)
+                .split(draw_loc)[0];
+
+            // Draw
+            f.render_stateful_widget(
+                Table::new(protocol_rows)
+                    .header(
+                        Row::new(PROTOCOL_HEADERS.to_vec())
+                            .style(self.colours.table_header_style)
+                            .bottom_margin(table_gap),
+                    )
+                    .block(protocol_block)
+                    .highlight_style(highlight_style)
+                    .style(self.colours.text_style)
+                    .widths(
+                        &(protocols_widget_state
+                            .table_width_state
+                            .calculated_column_widths
+                            .iter()
+                            .map(|calculated_width| Constraint::Length(*calculated_width))
+                            .collect::<Vec<_>>()),
+                    ),
+                margined_draw_loc,
+                protocols_table_state,
+            );
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                // Note there is no difference between this and using draw_loc, but I'm too lazy to fix it.
+                if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                    widget.top_left_corner = Some((margined_draw_loc.x, margined_draw_loc.y));
+                    widget.bottom_right_corner = Some((
+                        margined_draw_loc.x + margined_draw_loc.width,
+                        margined_draw_loc.y + margined_draw_loc.height,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn draw_tcp_retransmit_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(protocols_widget_state) =
+            app_state.protocols_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(protocols_widget_state.current_display_time as f64);
+
+            let display_time_labels = vec![
+                Span::styled(
+                    format!("{}s", protocols_widget_state.current_display_time / 1000),
+                    self.colours.graph_style,
+                ),
+                Span::styled("0s".to_string(), self.colours.graph_style),
+            ];
+
+            let x_axis = if app_state.app_config_fields.hide_time
+                || (app_state.app_config_fields.autohide_time
+                    && protocols_widget_state.autohide_timer.is_none())
+            {
+                Axis::default().bounds([time_start, 0.0])
+            } else if let Some(time) = protocols_widget_state.autohide_timer {
+                if std::time::Instant::now().duration_since(time).as_millis()
+                    < AUTOHIDE_TIMEOUT_MILLISECONDS.into()
+                {
+                    Axis::default()
+                        .bounds([time_start, 0.0])
+                        .style(self.colours.graph_style)
+                        .labels(display_time_labels)
+                } else {
+                    protocols_widget_state.autohide_timer = None;
+                    Axis::default().bounds([time_start, 0.0])
+                }
+            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
+                Axis::default().bounds([time_start, 0.0])
+            } else {
+                Axis::default()
+                    .bounds([time_start, 0.0])
+                    .style(self.colours.graph_style)
+                    .labels(display_time_labels)
+            };
+
+            let retransmit_points = &mut app_state.canvas_data.tcp_retransmit_data;
+
+            // Interpolate a point for the left edge of the graph to avoid ugly gaps
+            let interpolated_point = (|| {
+                let end_pos = retransmit_points
+                    .iter()
+                    .position(|(time, _data)| *time >= time_start)?;
+                if end_pos > 1 {
+                    let start_pos = end_pos - 1;
+                    let outside_point = *retransmit_points.get(start_pos)?;
+                    let inside_point = *retransmit_points.get(end_pos)?;
+                    let new_point = (
+                        time_start,
+                        interpolate_points(&outside_point, &inside_point, time_start),
+                    );
+
+                    *retransmit_points.get_mut(start_pos)? = new_point;
+                    Some((start_pos, outside_point))
+                } else {
+                    None // Point is already "leftmost", no need to interpolate.
+                }
+            })();
+
+            // Scale to the largest visible value, but never below 1.0.
+            let y_max = retransmit_points
+                .iter()
+                .filter(|(time, _data)| *time >= time_start)
+                .map(|(_time, data)| *data)
+                .fold(1.0_f64, f64::max)
+                .ceil();
+
+            let y_axis = Axis::default()
+                .style(self.colours.graph_style)
+                .bounds([0.0, y_max])
+                .labels(vec![
+                    Span::styled("0", self.colours.graph_style),
+                    Span::styled(format!("{}/s", y_max), self.colours.graph_style),
+                ]);
+
+            let current_rate = retransmit_points
+                .last()
+                .map(|(_time, data)| *data)
+                .unwrap_or(0.0);
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let border_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            f.render_widget(
+                Chart::new(vec![Dataset::default()
+                    .name(format!("{:.1}/s", current_rate))
+                    .marker(marker)
+                    .style(self.colours.rx_style)
+                    .data(retransmit_points)
+                    .graph_type(tui::widgets::GraphType::Line)])
+                .block(
+                    Block::default()
+                        .title(Spans::from(Span::styled(
+                            " TCP Retransmits ",
+                            self.colours.widget_title_style,
+                        )))
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                )
+                .x_axis(x_axis)
+                .y_axis(y_axis)
+                .hidden_legend_constraints((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                draw_loc,
+            );
+
+            // Now if you're done, reset the interpolated point!
+            if let Some((index, old_value)) = interpolated_point {
+                if let Some(to_replace) = app_state.canvas_data.tcp_retransmit_data.get_mut(index) {
+                    *to_replace = old_value;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{app::App, canvas::Painter};
+
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub trait SummaryWidget {
+    fn draw_summary<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl SummaryWidget for Painter {
+    fn draw_summary<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let summary_data = &app_state.canvas_data.summary_data;
+
+        let is_on_widget = widget_id == app_state.current_widget.widget_id;
+        let border_style = if is_on_widget {
+            self.colours.highlighted_border_style
+        } else {
+            self.colours.border_style
+        };
+
+        let title = if app_state.is_expanded {
+            const TITLE_BASE: &str = " Summary ── Esc to go back ";
+            Spans::from(vec![
+                Span::styled(" Summary ", self.colours.widget_title_style),
+                Span::styled(
+                    format!(
+                        "─{}─ Esc to go back ",
+                        "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                            UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
+                        ))
+                    ),
+                    border_style,
+                ),
+            ])
+        } else {
+            Spans::from(Span::styled(" Summary ", self.colours.widget_title_style))
+        };
+
+        let field = |label: &'static str, value: &str| {
+            vec![
+                Span::styled(label, self.colours.table_header_style),
+                Span::styled(value.to_string(), self.colours.text_style),
+                Span::raw("  "),
+            ]
+        };
+
+        let text = vec![
+            Spans::from(
+                [
+                    field("Host: ", &summary_data.hostname),
+                    field("Kernel: ", &summary_data.kernel_version),
+                    field("Uptime: ", &summary_data.uptime),
+                    field("Users: ", &summary_data.user_count),
+                    field("Needs restart: ", &summary_data.needs_restart),
+                ]
+                .concat(),
+            ),
+            Spans::from(
+                [
+                    field("Tasks: ", &summary_data.tasks),
+                    field("Load average: ", &summary_data.load_avg),
+                    field("Open files: ", &summary_data.file_descriptors),
+                ]
+                .concat(),
+            ),
+        ];
+
+        f.render_widget(
+            Paragraph::new(text)
+                .block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                )
+                .wrap(Wrap { trim: true }),
+            draw_loc,
+        );
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
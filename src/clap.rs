@@ -106,6 +106,15 @@ pub fn build_app() -> Command<'static> {
         .long_help("Sets the temperature type to Celsius. This is the default option.");
 
     // All flags. These are in alphabetical order
+    let all_partitions = Arg::new("all_partitions")
+        .long("all_partitions")
+        .help("Shows all partitions in the disk widget.")
+        .long_help(
+            "Shows all partitions in the disk widget, including network and virtual filesystems \
+            such as NFS, CIFS, tmpfs, overlay, and FUSE mounts. By default, only physical partitions \
+            are shown. These can be narrowed down by filesystem type using the 'fs_filter' config option.",
+        );
+
     let autohide_time = Arg::new("autohide_time")
         .long("autohide_time")
         .help("Temporarily shows the time scale in graphs.")
@@ -147,6 +156,24 @@ pub fn build_app() -> Command<'static> {
         .help("Disables mouse clicks.")
         .long_help("Disables mouse clicks from interacting with the program.");
 
+    let disk_full_warning = Arg::new("disk_full_warning")
+        .long("disk_full_warning")
+        .takes_value(true)
+        .value_name("SECS")
+        .help("Highlights disks that will be full within this time.")
+        .long_help(
+            "Highlights disks in the disk widget that are estimated to be full within this many \
+            seconds, based on how quickly they have been filling up recently. Defaults to 3600 (1 hour).",
+        );
+
+    let disk_tree = Arg::new("disk_tree")
+        .long("disk_tree")
+        .help("Defaults the disk widget be in tree mode.")
+        .long_help(
+            "Defaults to showing the disk widget as a tree of physical disks, partitions, \
+            and any dm/md devices (such as LVM, dm-crypt, or RAID) built on top of them.",
+        );
+
     let dot_marker = Arg::new("dot_marker")
         .short('m')
         .long("dot_marker")
@@ -165,6 +192,14 @@ pub fn build_app() -> Command<'static> {
         .help("Hides the average CPU usage.")
         .long_help("Hides the average CPU usage from being shown.");
 
+    let hide_kernel_threads = Arg::new("hide_kernel_threads")
+        .long("hide_kernel_threads")
+        .help("Hides kernel threads in the process widget by default.")
+        .long_help(
+            "Hides kernel threads in the process widget by default. This can still be toggled \
+            per widget with 'T'.",
+        );
+
     let hide_table_gap = Arg::new("hide_table_gap")
         .long("hide_table_gap")
         .help("Hides spacing between table headers and entries.")
@@ -353,6 +388,21 @@ use CPU (3) as the default instead.
             "Displays the network widget with binary prefixes (i.e. kibibits, mebibits) rather than a decimal prefix (i.e. kilobits, megabits). Defaults to decimal prefixes.",
         );
 
+    let network_packet_graph = Arg::new("network_packet_graph")
+        .long("network_packet_graph")
+        .help("Shows a packets per second graph in the network widget.")
+        .long_help(
+            "Shows a graph of packets per second below the network usage graph in the network widget.",
+        );
+
+    let normalize_load_avg = Arg::new("normalize_load_avg")
+        .long("normalize_load_avg")
+        .help("Divides load averages by the number of cores.")
+        .long_help(
+            "Divides the load averages shown in the load average widget by the number of cores, \
+            so that 1.0 means that the system is fully loaded.",
+        );
+
     let app = Command::new(crate_name!())
         .version(crate_version!())
         .author(crate_authors!())
@@ -367,6 +417,7 @@ use CPU (3) as the default instead.
         .arg(fahrenheit)
         .arg(celsius)
         .group(ArgGroup::new("TEMPERATURE_TYPE").args(&["kelvin", "fahrenheit", "celsius"]))
+        .arg(all_partitions)
         .arg(autohide_time)
         .arg(basic)
         .arg(case_sensitive)
@@ -378,9 +429,12 @@ use CPU (3) as the default instead.
         .arg(default_widget_count)
         .arg(default_widget_type)
         .arg(disable_click)
+        .arg(disk_full_warning)
+        .arg(disk_tree)
         .arg(dot_marker)
         .arg(group)
         .arg(hide_avg_cpu)
+        .arg(hide_kernel_threads)
         .arg(hide_table_gap)
         .arg(hide_time)
         .arg(show_table_scroll_position)
@@ -393,6 +447,8 @@ use CPU (3) as the default instead.
         .arg(network_use_bytes)
         .arg(network_use_log)
         .arg(network_use_binary_prefix)
+        .arg(network_packet_graph)
+        .arg(normalize_load_avg)
         .arg(current_usage)
         .arg(sort_cpu_hist)
         .arg(use_old_network_legend)
@@ -13,6 +13,9 @@ pub const STALE_MIN_MILLISECONDS: u64 = 30 * 1000; // Lowest is 30 seconds
 pub const TIME_CHANGE_MILLISECONDS: u64 = 15 * 1000; // How much to increment each time
 pub const AUTOHIDE_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to autohide
 
+// Warn when a disk is estimated to be full within this time
+pub const DEFAULT_DISK_FULL_WARNING_SECONDS: u64 = 60 * 60; // Defaults to 1 hour.
+
 pub const TICK_RATE_IN_MILLISECONDS: u64 = 200;
 // How fast the screen refreshes
 pub const DEFAULT_REFRESH_RATE_IN_MILLISECONDS: u64 = 1000;
@@ -99,6 +102,11 @@ pub static GRUVBOX_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColo
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#fabd2f".to_string()),
     low_battery_color: Some("#fb4934".to_string()),
+    disk_full_warning_color: Some("#fb4934".to_string()),
+    zombie_process_color: Some("#d3869b".to_string()),
+    uninterruptible_process_color: Some("#fabd2f".to_string()),
+    fd_warning_color: Some("#fb4934".to_string()),
+    stopped_process_color: Some("#83a598".to_string()),
 });
 
 pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -144,6 +152,11 @@ pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| Conf
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#d79921".to_string()),
     low_battery_color: Some("#cc241d".to_string()),
+    disk_full_warning_color: Some("#cc241d".to_string()),
+    zombie_process_color: Some("#8f3f71".to_string()),
+    uninterruptible_process_color: Some("#b57614".to_string()),
+    fd_warning_color: Some("#cc241d".to_string()),
+    stopped_process_color: Some("#076678".to_string()),
 });
 
 pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -177,6 +190,11 @@ pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    disk_full_warning_color: Some("#bf616a".to_string()),
+    zombie_process_color: Some("#b48ead".to_string()),
+    uninterruptible_process_color: Some("#ebcb8b".to_string()),
+    fd_warning_color: Some("#bf616a".to_string()),
+    stopped_process_color: Some("#81a1c1".to_string()),
 });
 
 pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -210,10 +228,15 @@ pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigC
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    disk_full_warning_color: Some("#bf616a".to_string()),
+    zombie_process_color: Some("#b48ead".to_string()),
+    uninterruptible_process_color: Some("#d08770".to_string()),
+    fd_warning_color: Some("#bf616a".to_string()),
+    stopped_process_color: Some("#5e81ac".to_string()),
 });
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 8] = [
+pub const HELP_CONTENTS_TEXT: [&str; 10] = [
     "Press the corresponding numbers to jump to the section, or scroll:",
     "1 - General",
     "2 - CPU widget",
@@ -222,6 +245,8 @@ pub const HELP_CONTENTS_TEXT: [&str; 8] = [
     "5 - Process sort widget",
     "6 - Battery widget",
     "7 - Basic memory widget",
+    "8 - Disk widget",
+    "9 - Protocol widget",
 ];
 
 // TODO [Help]: Search in help?
@@ -265,7 +290,7 @@ pub const CPU_HELP_TEXT: [&str; 2] = [
     "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
 ];
 
-pub const PROCESS_HELP_TEXT: [&str; 15] = [
+pub const PROCESS_HELP_TEXT: [&str; 25] = [
     "3 - Process widget",
     "dd, F9           Kill the selected process",
     "c                Sort by CPU usage, press again to reverse sorting order",
@@ -279,11 +304,21 @@ pub const PROCESS_HELP_TEXT: [&str; 15] = [
     "I                Invert current sort",
     "%                Toggle between values and percentages for memory usage",
     "t, F5            Toggle tree mode",
+    "v                Toggle the I/O graph for the selected process",
+    "a                Set the CPU affinity of the selected process",
+    "o                Adjust the OOM score of the selected process",
+    "F                Show the open files of the selected process",
+    "x                Suspend the selected process (SIGSTOP)",
+    "X                Resume the selected process (SIGCONT)",
+    "u                Group/un-group processes by systemd unit",
+    "T                Hide/show kernel threads",
+    "z                Only show zombie and uninterruptible processes",
     "+, -, click      Collapse/expand a branch while in tree mode",
     "click on header  Sorts the entries by that column, click again to invert the sort",
+    "right click      Suspend the clicked process, or resume it if it is stopped",
 ];
 
-pub const SEARCH_HELP_TEXT: [&str; 48] = [
+pub const SEARCH_HELP_TEXT: [&str; 60] = [
     "4 - Process search widget",
     "Esc              Close the search widget (retains the filter)",
     "Ctrl-a           Skip to the start of the search query",
@@ -311,6 +346,18 @@ pub const SEARCH_HELP_TEXT: [&str; 48] = [
     "twrite, t.write  ex: twrite = 1",
     "user            ex: user = root",
     "state            ex: state = running",
+    "oom              ex: oom > 500",
+    "oomadj           ex: oomadj < 0",
+    "unit             ex: unit = sshd.service",
+    "kthread          ex: kthread = false",
+    "minflt           ex: minflt > 1000",
+    "majflt           ex: majflt > 0",
+    "vcsw             ex: vcsw > 100",
+    "ivcsw            ex: ivcsw > 100",
+    "deleted          ex: deleted = true",
+    "tty              ex: tty = pts/0",
+    "sid              ex: sid = 1234",
+    "pgid             ex: pgid = 1234",
     "",
     "Comparison operators:",
     "=                ex: cpu = 1",
@@ -343,10 +390,11 @@ pub const SORT_HELP_TEXT: [&str; 6] = [
     "Enter            Sort by current selected column",
 ];
 
-pub const BATTERY_HELP_TEXT: [&str; 3] = [
+pub const BATTERY_HELP_TEXT: [&str; 4] = [
     "6 - Battery widget",
     "Left             Go to previous battery",
     "Right            Go to next battery",
+    "v                Toggle between the table and graphs",
 ];
 
 pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
@@ -354,6 +402,13 @@ pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%                Toggle between values and percentages for memory usage",
 ];
 
+pub const DISK_HELP_TEXT: [&str; 2] = ["8 - Disk widget", "t                Toggle tree mode"];
+
+pub const PROTOCOL_HELP_TEXT: [&str; 2] = [
+    "9 - Protocol widget",
+    "v                Toggle the TCP retransmit graph",
+];
+
 pub const HELP_TEXT: &[&[&str]] = &[
     &HELP_CONTENTS_TEXT,
     &GENERAL_HELP_TEXT,
@@ -363,6 +418,8 @@ pub const HELP_TEXT: &[&[&str]] = &[
     &SORT_HELP_TEXT,
     &BATTERY_HELP_TEXT,
     &BASIC_MEM_HELP_TEXT,
+    &DISK_HELP_TEXT,
+    &PROTOCOL_HELP_TEXT,
 ];
 
 // Default layouts
@@ -489,14 +546,30 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #show_table_scroll_position = false
 # Show processes as their commands by default in the process widget.
 #process_command = false
+# Hides kernel threads in the process widget by default.
+#hide_kernel_threads = false
 # Displays the network widget with binary prefixes.
 #network_use_binary_prefix = false
 # Displays the network widget using bytes.
 #network_use_bytes = false
 # Displays the network widget with a log scale.
 #network_use_log = false
+# Shows a packets per second graph in the network widget.
+#network_packet_graph = false
 # Hides advanced options to stop a process on Unix-like systems.
 #disable_advanced_kill = false
+# Shows all partitions in the disk widget, including network and virtual filesystems.
+#all_partitions = false
+# Show tree mode by default in the disk widget.
+#disk_tree = false
+# Highlights disks estimated to be full within this many seconds.
+#disk_full_warning = 3600
+# Divides load averages in the load average widget by the number of cores.
+#normalize_load_avg = false
+# Extra process columns to show: "rchar", "wchar", "syscr", "syscw", "cancelled_write_bytes",
+# "affinity", "last_cpu", "oom_score", "oom_score_adj", "unit", "minflt", "majflt", "vcsw", "ivcsw",
+# "fds", "fd%", "deleted", "tty", "sid", "pgid".
+#process_columns = ["rchar", "wchar"]
 
 # These are all the components that support custom theming.  Note that colour support
 # will depend on terminal support.
@@ -534,6 +607,14 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #high_battery_color="green"
 #medium_battery_color="yellow"
 #low_battery_color="red"
+# Represents the colour of disks estimated to be full soon.
+#disk_full_warning_color="red"
+# Represents the colours of zombie (Z), uninterruptible (D), and stopped (T) processes.
+#zombie_process_color="magenta"
+#uninterruptible_process_color="yellow"
+#stopped_process_color="blue"
+# Represents the colour of processes close to their open file descriptor limit.
+#fd_warning_color="red"
 
 # Layout - layouts follow a pattern like this:
 # [[row]] represents a row in the application.
@@ -583,6 +664,13 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #case_sensitive = false
 #whole_word = false
 
+#[fs_filter]
+#is_list_ignored = true
+#list = ["tmpfs", "overlay", "squashfs"]
+#regex = false
+#case_sensitive = false
+#whole_word = true
+
 #[temp_filter]
 #is_list_ignored = true
 #list = ["cpu", "wifi"]
@@ -8,7 +8,10 @@ use crate::{
 use data_harvester::processes::ProcessSorting;
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::Hash,
+};
 
 /// Point is of time, data
 type Point = (f64, f64);
@@ -21,6 +24,16 @@ pub struct ConvertedBatteryData {
     pub duration_until_full: Option<String>,
     pub duration_until_empty: Option<String>,
     pub health: String,
+    pub state: String,
+    pub cycle_count: String,
+    pub energy_full: String,
+    pub energy_full_design: String,
+    pub voltage: String,
+    pub technology: String,
+    pub vendor: String,
+    pub model: String,
+    pub charge_data: Vec<Point>,
+    pub consumption_data: Vec<Point>,
 }
 
 #[derive(Default, Debug)]
@@ -31,6 +44,13 @@ pub struct ConvertedNetworkData {
     pub tx_display: String,
     pub total_rx_display: Option<String>,
     pub total_tx_display: Option<String>,
+    pub rx_packets: Vec<Point>,
+    pub tx_packets: Vec<Point>,
+    pub rx_packets_display: String,
+    pub tx_packets_display: String,
+    pub errors_display: String,
+    pub drops_display: String,
+    pub wireless_displays: Vec<String>,
     // TODO: [NETWORKING] add min/max/mean of each
     // min_rx : f64,
     // max_rx : f64,
@@ -64,6 +84,29 @@ pub struct ConvertedProcessData {
     pub process_state: String,
     pub process_char: char,
     pub user: Option<String>,
+    pub read_chars: u64,
+    pub write_chars: u64,
+    pub read_syscalls: u64,
+    pub write_syscalls: u64,
+    pub cancelled_write_bytes: u64,
+    pub cpus_allowed_list: String,
+    pub last_cpu: Option<u32>,
+    pub oom_score: Option<u32>,
+    pub oom_score_adj: Option<i32>,
+    pub unit: Option<String>,
+    pub is_kernel_thread: bool,
+    pub minor_faults_per_sec: f64,
+    pub major_faults_per_sec: f64,
+    pub voluntary_switches_per_sec: f64,
+    pub involuntary_switches_per_sec: f64,
+    pub fd_count: Option<u64>,
+    /// Open file descriptors as a percentage of the soft limit.
+    pub fd_percent: Option<f64>,
+    pub is_exe_deleted: bool,
+    pub has_deleted_libraries: bool,
+    pub tty: Option<String>,
+    pub session_id: Option<Pid>,
+    pub process_group_id: Option<Pid>,
 
     /// Prefix printed before the process when displayed.
     pub process_description_prefix: Option<String>,
@@ -83,6 +126,18 @@ pub struct ConvertedCpuData {
     pub legend_value: String,
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedSummaryData {
+    pub hostname: String,
+    pub kernel_version: String,
+    pub uptime: String,
+    pub user_count: String,
+    pub tasks: String,
+    pub load_avg: String,
+    pub file_descriptors: String,
+    pub needs_restart: String,
+}
+
 pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
     let current_data = &app.data_collection;
     let temp_type = &app.app_config_fields.temperature_type;
@@ -110,54 +165,255 @@ pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
     sensor_vector
 }
 
-pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
-    let mut disk_vector: Vec<Vec<String>> = Vec::new();
+/// A node in the disk tree: either a block device (by kernel name), or an extra mount of one.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum DiskTreeNode {
+    Device(String),
+    Mount(usize),
+}
+
+/// Formats a duration as its two most significant units, i.e. `3h 12m`.
+fn get_short_duration(secs: u64) -> String {
+    let (days, hours, minutes, seconds) = (
+        secs / 86400,
+        (secs % 86400) / 3600,
+        (secs % 3600) / 60,
+        secs % 60,
+    );
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+fn get_disk_row(
+    current_data: &data_farmer::DataCollection, disk: &data_harvester::disks::DiskHarvest,
+    name: String, io_read: &str, io_write: &str,
+) -> Vec<String> {
+    let free_space_fmt = if let Some(free_space) = disk.free_space {
+        let converted_free_space = get_decimal_bytes(free_space);
+        format!("{:.*}{}", 0, converted_free_space.0, converted_free_space.1)
+    } else {
+        "N/A".to_string()
+    };
+    let total_space_fmt = if let Some(total_space) = disk.total_space {
+        let converted_total_space = get_decimal_bytes(total_space);
+        format!(
+            "{:.*}{}",
+            0, converted_total_space.0, converted_total_space.1
+        )
+    } else {
+        "N/A".to_string()
+    };
+
+    let usage_fmt =
+        if let (Some(used_space), Some(total_space)) = (disk.used_space, disk.total_space) {
+            format!("{:.0}%", used_space as f64 / total_space as f64 * 100_f64)
+        } else {
+            "N/A".to_string()
+        };
+
+    let full_in_fmt = if let Some(secs_until_full) = current_data.get_secs_until_disk_full(disk) {
+        get_short_duration(secs_until_full)
+    } else {
+        "N/A".to_string()
+    };
+
+    vec![
+        name,
+        disk.mount_point.to_string(),
+        usage_fmt,
+        free_space_fmt,
+        total_space_fmt,
+        full_in_fmt,
+        io_read.to_string(),
+        io_write.to_string(),
+    ]
+}
 
+/// Returns whether a disk is estimated to be full within the warning horizon.
+fn is_disk_filling(
+    current_data: &data_farmer::DataCollection, disk: &data_harvester::disks::DiskHarvest,
+    disk_full_warning: u64,
+) -> bool {
     current_data
-        .disk_harvest
-        .iter()
-        .zip(&current_data.io_labels)
-        .for_each(|(disk, (io_read, io_write))| {
-            let free_space_fmt = if let Some(free_space) = disk.free_space {
-                let converted_free_space = get_decimal_bytes(free_space);
-                format!("{:.*}{}", 0, converted_free_space.0, converted_free_space.1)
-            } else {
-                "N/A".to_string()
-            };
-            let total_space_fmt = if let Some(total_space) = disk.total_space {
-                let converted_total_space = get_decimal_bytes(total_space);
-                format!(
-                    "{:.*}{}",
-                    0, converted_total_space.0, converted_total_space.1
-                )
-            } else {
-                "N/A".to_string()
-            };
+        .get_secs_until_disk_full(disk)
+        .map_or(false, |secs_until_full| secs_until_full < disk_full_warning)
+}
 
-            let usage_fmt = if let (Some(used_space), Some(total_space)) =
-                (disk.used_space, disk.total_space)
-            {
-                format!("{:.0}%", used_space as f64 / total_space as f64 * 100_f64)
-            } else {
-                "N/A".to_string()
-            };
+/// Returns the kernel name of a disk (i.e. `dm-3` for `/dev/dm-3`).
+fn get_disk_kernel_name(disk: &data_harvester::disks::DiskHarvest) -> &str {
+    disk.name.split('/').last().unwrap_or(&disk.name)
+}
 
-            disk_vector.push(vec![
-                disk.name.to_string(),
-                disk.mount_point.to_string(),
-                usage_fmt,
-                free_space_fmt,
-                total_space_fmt,
-                io_read.to_string(),
-                io_write.to_string(),
-            ]);
-        });
+/// Returns the friendly name of a disk if one was found (i.e. its `/dev/mapper` name), otherwise its
+/// usual name.
+fn get_disk_display_name(
+    current_data: &data_farmer::DataCollection, disk: &data_harvester::disks::DiskHarvest,
+) -> String {
+    current_data
+        .block_device_harvest
+        .get(get_disk_kernel_name(disk))
+        .and_then(|block_device| block_device.friendly_name.clone())
+        .unwrap_or_else(|| disk.name.to_string())
+}
+
+/// Returns the rows of the disk table, along with whether each row is estimated to be full within
+/// `disk_full_warning` seconds.
+pub fn convert_disk_row(
+    current_data: &data_farmer::DataCollection, is_tree: bool, disk_full_warning: u64,
+) -> (Vec<Vec<String>>, Vec<bool>) {
+    let (mut disk_vector, disk_warnings): (Vec<Vec<String>>, Vec<bool>) = if is_tree {
+        convert_disk_tree(current_data, disk_full_warning)
+    } else {
+        current_data
+            .disk_harvest
+            .iter()
+            .zip(&current_data.io_labels)
+            .map(|(disk, (io_read, io_write))| {
+                (
+                    get_disk_row(
+                        current_data,
+                        disk,
+                        get_disk_display_name(current_data, disk),
+                        io_read,
+                        io_write,
+                    ),
+                    is_disk_filling(current_data, disk, disk_full_warning),
+                )
+            })
+            .unzip()
+    };
 
     if disk_vector.is_empty() {
         disk_vector.push(vec!["No Disks Found".to_string(), "".to_string()]);
     }
 
-    disk_vector
+    (disk_vector, disk_warnings)
+}
+
+/// Lays the disks out as a tree of physical disk → partition → dm/md device → mount.  Block devices
+/// without any mounted descendants are pruned, and disks that aren't block devices (i.e. network
+/// mounts) are shown as their own roots.
+fn convert_disk_tree(
+    current_data: &data_farmer::DataCollection, disk_full_warning: u64,
+) -> (Vec<Vec<String>>, Vec<bool>) {
+    let block_devices = &current_data.block_device_harvest;
+
+    let mut mount_mapping: HashMap<&str, Vec<usize>> = HashMap::default();
+    for (itx, disk) in current_data.disk_harvest.iter().enumerate() {
+        mount_mapping
+            .entry(get_disk_kernel_name(disk))
+            .or_default()
+            .push(itx);
+    }
+
+    fn has_mounts(
+        device: &str, block_devices: &data_harvester::disks::BlockDeviceMap,
+        mount_mapping: &HashMap<&str, Vec<usize>>,
+    ) -> bool {
+        mount_mapping.contains_key(device)
+            || block_devices.get(device).map_or(false, |block_device| {
+                block_device
+                    .children
+                    .iter()
+                    .any(|child| has_mounts(child, block_devices, mount_mapping))
+            })
+    }
+
+    // Build the parent -> child mapping.  Children are inserted in reverse as the traversal visits
+    // them in reverse.
+    let mut parent_child_mapping: HashMap<DiskTreeNode, IndexSet<DiskTreeNode, FxBuildHasher>> =
+        HashMap::default();
+    let mut roots = Vec::new();
+
+    let mut device_names: Vec<&String> = block_devices
+        .keys()
+        .filter(|device| has_mounts(device, block_devices, &mount_mapping))
+        .collect();
+    device_names.sort();
+
+    for device in device_names {
+        let block_device = &block_devices[device];
+        let mut children: IndexSet<DiskTreeNode, FxBuildHasher> =
+            IndexSet::with_hasher(FxBuildHasher::default());
+
+        if let Some(mounts) = mount_mapping.get(device.as_str()) {
+            for &itx in mounts.iter().skip(1).rev() {
+                children.insert(DiskTreeNode::Mount(itx));
+            }
+        }
+        for child in block_device.children.iter().rev() {
+            if has_mounts(child, block_devices, &mount_mapping) {
+                children.insert(DiskTreeNode::Device(child.clone()));
+            }
+        }
+        parent_child_mapping.insert(DiskTreeNode::Device(device.clone()), children);
+
+        if block_device.parents.is_empty() {
+            roots.push(DiskTreeNode::Device(device.clone()));
+        }
+    }
+
+    for (itx, disk) in current_data.disk_harvest.iter().enumerate() {
+        if !block_devices.contains_key(get_disk_kernel_name(disk)) {
+            roots.push(DiskTreeNode::Mount(itx));
+        }
+    }
+
+    let mut disk_vector = Vec::new();
+    let mut disk_warnings = Vec::new();
+    for root in roots {
+        let (nodes, branch_res) = build_explored_tree(&root, &parent_child_mapping, "", None);
+        let lines = std::iter::once(String::default()).chain(branch_res);
+
+        for (node, prefix) in nodes.iter().zip(lines) {
+            let itx = match node {
+                DiskTreeNode::Device(device) => mount_mapping
+                    .get(device.as_str())
+                    .and_then(|mounts| mounts.first().copied()),
+                DiskTreeNode::Mount(itx) => Some(*itx),
+            };
+
+            if let Some(itx) = itx {
+                let disk = &current_data.disk_harvest[itx];
+                let (io_read, io_write) = current_data
+                    .io_labels
+                    .get(itx)
+                    .map(|(io_read, io_write)| (io_read.as_str(), io_write.as_str()))
+                    .unwrap_or(("", ""));
+                let name = format!("{}{}", prefix, get_disk_display_name(current_data, disk));
+
+                disk_vector.push(get_disk_row(current_data, disk, name, io_read, io_write));
+                disk_warnings.push(is_disk_filling(current_data, disk, disk_full_warning));
+            } else if let DiskTreeNode::Device(device) = node {
+                let name = block_devices
+                    .get(device)
+                    .and_then(|block_device| block_device.friendly_name.clone())
+                    .unwrap_or_else(|| format!("/dev/{}", device));
+
+                disk_vector.push(vec![
+                    format!("{}{}", prefix, name),
+                    String::default(),
+                    String::default(),
+                    String::default(),
+                    String::default(),
+                    String::default(),
+                    String::default(),
+                    String::default(),
+                ]);
+                disk_warnings.push(false);
+            }
+        }
+    }
+
+    (disk_vector, disk_warnings)
 }
 
 pub fn convert_cpu_data_points(
@@ -301,6 +557,46 @@ pub fn convert_mem_data_points(
     result
 }
 
+/// Returns the 1, 5, and 15 minute load averages over time, optionally divided by the number of
+/// cores.
+pub fn convert_load_avg_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool, normalize: bool,
+) -> [Vec<Point>; 3] {
+    let mut result: [Vec<Point>; 3] = Default::default();
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+    let divisor = if normalize {
+        current_data
+            .cpu_harvest
+            .iter()
+            .filter(|cpu| cpu.cpu_count.is_some())
+            .count()
+            .max(1) as f64
+    } else {
+        1.0
+    };
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for (points, load_avg) in result.iter_mut().zip(data.load_avg_data) {
+            points.push((-time_from_start, f64::from(load_avg) / divisor));
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
 pub fn convert_swap_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
 ) -> Vec<Point> {
@@ -454,6 +750,56 @@ pub fn get_rx_tx_data_points(
     (rx, tx)
 }
 
+/// Returns the received and transmitted packets per second over time.
+pub fn get_packet_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> (Vec<Point>, Vec<Point>) {
+    let mut rx_packets: Vec<Point> = Vec::new();
+    let mut tx_packets: Vec<Point> = Vec::new();
+
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+
+        rx_packets.push((-time_from_start, data.rx_packets_data));
+        tx_packets.push((-time_from_start, data.tx_packets_data));
+        if *time == current_time {
+            break;
+        }
+    }
+
+    (rx_packets, tx_packets)
+}
+
+/// Formats a packet count, using a decimal prefix once it gets into the thousands.
+fn get_packet_count_string(count: u64) -> String {
+    let (converted_count, prefix) = get_decimal_prefix(count, "");
+    if prefix.is_empty() {
+        count.to_string()
+    } else {
+        format!("{:.1}{}", converted_count, prefix)
+    }
+}
+
+/// Formats a per-second packet rate, keeping a decimal place so that low rates are not shown as 0.
+fn get_packet_rate_string(rate: f64) -> String {
+    let (converted_rate, prefix) = get_decimal_prefix(rate as u64, "");
+    if prefix.is_empty() {
+        format!("{:.1}", rate)
+    } else {
+        format!("{:.1}{}", converted_rate, prefix)
+    }
+}
+
 pub fn convert_network_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool, need_four_points: bool,
     network_scale_type: &AxisScaling, network_unit_type: &DataUnit,
@@ -466,6 +812,41 @@ pub fn convert_network_data_points(
         network_unit_type,
         network_use_binary_prefix,
     );
+    let (rx_packets, tx_packets) = get_packet_data_points(current_data, is_frozen);
+
+    let total_packets = &current_data.network_harvest.total_packets;
+    let (rx_packets_rate, tx_packets_rate, errors_rate, drops_rate) =
+        if let Some((_time, data)) = current_data.timed_data_vec.last() {
+            (
+                data.rx_packets_data,
+                data.tx_packets_data,
+                data.rx_errors_data + data.tx_errors_data,
+                data.rx_drops_data + data.tx_drops_data,
+            )
+        } else {
+            (0.0, 0.0, 0.0, 0.0)
+        };
+    let rx_packets_display = format!("{}/s", get_packet_rate_string(rx_packets_rate));
+    let tx_packets_display = format!("{}/s", get_packet_rate_string(tx_packets_rate));
+    let errors_display = format!(
+        "{} (+{}/s)",
+        get_packet_count_string(total_packets.rx_errors + total_packets.tx_errors),
+        get_packet_rate_string(errors_rate)
+    );
+    let drops_display = format!(
+        "{} (+{}/s)",
+        get_packet_count_string(total_packets.rx_drops + total_packets.tx_drops),
+        get_packet_rate_string(drops_rate)
+    );
+
+    let wireless_displays = current_data
+        .wireless_harvest
+        .iter()
+        .filter_map(|wireless| {
+            get_wireless_string(wireless)
+                .map(|wireless_string| format!("{}: {}", wireless.name, wireless_string))
+        })
+        .collect();
 
     let unit = match network_unit_type {
         DataUnit::Byte => "B/s",
@@ -531,6 +912,13 @@ pub fn convert_network_data_points(
             tx_display,
             total_rx_display,
             total_tx_display,
+            rx_packets,
+            tx_packets,
+            rx_packets_display,
+            tx_packets_display,
+            errors_display,
+            drops_display,
+            wireless_displays,
         }
     } else {
         let rx_display = format!(
@@ -579,8 +967,315 @@ pub fn convert_network_data_points(
             tx_display,
             total_rx_display: None,
             total_tx_display: None,
+            rx_packets,
+            tx_packets,
+            rx_packets_display,
+            tx_packets_display,
+            errors_display,
+            drops_display,
+            wireless_displays,
+        }
+    }
+}
+
+/// The maximum link quality reported by most wireless drivers.
+const WIRELESS_LINK_QUALITY_MAX: f64 = 70.0;
+
+/// The number of samples shown in sparklines.
+const SPARKLINE_LENGTH: usize = 8;
+
+/// Returns a small text graph of the given values, scaled from 0 to `max`.
+fn get_sparkline(values: &[f64], max: f64) -> String {
+    const SPARKLINE_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    values
+        .iter()
+        .map(|value| {
+            let ratio = if max > 0.0 { value / max } else { 0.0 };
+            let index = (ratio * (SPARKLINE_CHARS.len() - 1) as f64).round() as usize;
+            SPARKLINE_CHARS[index.min(SPARKLINE_CHARS.len() - 1)]
+        })
+        .collect()
+}
+
+/// Returns the most recent link qualities of the wireless interface named `name`, oldest first.
+fn get_wireless_history(
+    current_data: &data_farmer::DataCollection, name: &str, samples: usize,
+) -> Vec<f64> {
+    let mut history: Vec<f64> = current_data
+        .timed_data_vec
+        .iter()
+        .rev()
+        .filter_map(|(_time, data)| data.wireless_data.get(name).copied())
+        .take(samples)
+        .collect();
+    history.reverse();
+
+    history
+}
+
+/// Returns the link quality, signal level, and noise level of a wireless interface, such as
+/// `54 -56dBm, noise -95dBm`.
+fn get_wireless_string(wireless: &data_harvester::network::WirelessHarvest) -> Option<String> {
+    let link_quality = wireless.link_quality?;
+    Some(match (wireless.signal_level, wireless.noise_level) {
+        (Some(signal_level), Some(noise_level)) => format!(
+            "{:.0} {:.0}dBm, noise {:.0}dBm",
+            link_quality, signal_level, noise_level
+        ),
+        (Some(signal_level), None) => format!("{:.0} {:.0}dBm", link_quality, signal_level),
+        (None, Some(noise_level)) => format!("{:.0}, noise {:.0}dBm", link_quality, noise_level),
+        (None, None) => format!("{:.0}", link_quality),
+    })
+}
+
+/// Returns the rows of the network interface table.
+pub fn convert_interface_row(
+    current_data: &data_farmer::DataCollection, network_unit_type: &DataUnit,
+    network_use_binary_prefix: bool,
+) -> Vec<Vec<String>> {
+    let unit = match network_unit_type {
+        DataUnit::Byte => "B/s",
+        DataUnit::Bit => "b/s",
+    };
+    let get_rate_string = |rate: u64| {
+        let rate = match network_unit_type {
+            DataUnit::Byte => rate / 8,
+            DataUnit::Bit => rate,
+        };
+        let converted_rate = if network_use_binary_prefix {
+            get_binary_prefix(rate, unit)
+        } else {
+            get_decimal_prefix(rate, unit)
+        };
+        format!("{:.1}{}", converted_rate.0, converted_rate.1)
+    };
+
+    let mut interface_vector: Vec<Vec<String>> = current_data
+        .interface_harvest
+        .iter()
+        .map(|interface| {
+            let speed = match (interface.speed_mbps, &interface.duplex) {
+                (Some(speed), Some(duplex)) => format!("{}Mb/s {}", speed, duplex),
+                (Some(speed), None) => format!("{}Mb/s", speed),
+                _ => "N/A".to_string(),
+            };
+            let wireless = match current_data
+                .wireless_harvest
+                .iter()
+                .find(|wireless| wireless.name == interface.name)
+            {
+                Some(wireless) => match get_wireless_string(wireless) {
+                    Some(wireless_string) => {
+                        let history =
+                            get_wireless_history(current_data, &interface.name, SPARKLINE_LENGTH);
+                        let max = history
+                            .iter()
+                            .fold(WIRELESS_LINK_QUALITY_MAX, |a, b| a.max(*b));
+                        format!("{} {}", wireless_string, get_sparkline(&history, max))
+                    }
+                    None => "N/A".to_string(),
+                },
+                None => "".to_string(),
+            };
+            let addresses = interface
+                .ipv4_addresses
+                .iter()
+                .chain(interface.ipv6_addresses.iter())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            vec![
+                interface.name.clone(),
+                interface
+                    .link_state
+                    .clone()
+                    .unwrap_or_else(|| "N/A".to_string()),
+                get_rate_string(interface.rx),
+                get_rate_string(interface.tx),
+                wireless,
+                interface
+                    .mtu
+                    .map(|mtu| mtu.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+                speed,
+                interface
+                    .mac_address
+                    .clone()
+                    .unwrap_or_else(|| "N/A".to_string()),
+                if addresses.is_empty() {
+                    "N/A".to_string()
+                } else {
+                    addresses
+                },
+            ]
+        })
+        .collect();
+
+    if interface_vector.is_empty() {
+        interface_vector.push(vec![
+            "No Interfaces Found".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]);
+    }
+
+    interface_vector
+}
+
+/// Returns the rows of the protocol statistics table.
+pub fn convert_protocol_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
+    let mut protocol_vector: Vec<Vec<String>> = current_data
+        .protocol_harvest
+        .iter()
+        .zip(&current_data.protocol_rates)
+        .map(|(stat, rate)| {
+            vec![
+                stat.name.to_string(),
+                format!("{:.1}/s", rate),
+                get_packet_count_string(stat.total),
+            ]
+        })
+        .collect();
+
+    if protocol_vector.is_empty() {
+        protocol_vector.push(vec![
+            "No Statistics Found".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]);
+    }
+
+    protocol_vector
+}
+
+fn get_socket_history(
+    current_data: &data_farmer::DataCollection, socket_index: usize, samples: usize,
+) -> Vec<f64> {
+    let mut history: Vec<f64> = current_data
+        .timed_data_vec
+        .iter()
+        .rev()
+        .filter_map(|(_time, data)| data.socket_data.get(socket_index).copied().flatten())
+        .take(samples)
+        .collect();
+    history.reverse();
+
+    history
+}
+
+/// Returns the rows of the socket table.
+pub fn convert_socket_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
+    let mut socket_vector: Vec<Vec<String>> = current_data
+        .socket_harvest
+        .iter()
+        .enumerate()
+        .filter_map(|(itx, stat)| {
+            let value = stat.value?;
+            let value = if stat.is_bytes {
+                let converted_value = get_binary_bytes(value);
+                format!("{:.1}{}", converted_value.0, converted_value.1)
+            } else {
+                value.to_string()
+            };
+            // Scale between the smallest and largest recent values so small changes in large
+            // counts are still visible.
+            let history = get_socket_history(current_data, itx, SPARKLINE_LENGTH);
+            let min = history.iter().fold(f64::MAX, |a, b| a.min(*b));
+            let trend = history.iter().map(|value| value - min).collect::<Vec<_>>();
+            let max = trend.iter().fold(0.0_f64, |a, b| a.max(*b));
+
+            Some(vec![
+                stat.name.to_string(),
+                value,
+                get_sparkline(&trend, max),
+            ])
+        })
+        .collect();
+
+    if socket_vector.is_empty() {
+        socket_vector.push(vec![
+            "No Statistics Found".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]);
+    }
+
+    socket_vector
+}
+
+pub fn convert_tcp_retransmit_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<Point> {
+    let mut result: Vec<Point> = Vec::new();
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        result.push((-time_from_start, data.tcp_retransmit_data));
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Returns the read and write rate points of the given processes, summed together.
+pub fn convert_process_io_data_points(
+    current_data: &data_farmer::DataCollection, pids: &[Pid], is_frozen: bool,
+) -> (Vec<Point>, Vec<Point>) {
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    // All processes are harvested at the same time, so entries line up by instant.
+    let mut totals: BTreeMap<std::time::Instant, (u64, u64)> = BTreeMap::new();
+    for pid in pids {
+        if let Some(history) = current_data.process_io_history.get(pid) {
+            for (time, read_per_sec, write_per_sec) in history {
+                if *time <= current_time {
+                    let total = totals.entry(*time).or_default();
+                    total.0 += read_per_sec;
+                    total.1 += write_per_sec;
+                }
+            }
         }
     }
+
+    totals
+        .iter()
+        .map(|(time, (read_per_sec, write_per_sec))| {
+            let time_from_start: f64 =
+                (current_time.duration_since(*time).as_millis() as f64).floor();
+            (
+                (-time_from_start, *read_per_sec as f64),
+                (-time_from_start, *write_per_sec as f64),
+            )
+        })
+        .unzip()
 }
 
 pub enum ProcessGroupingType {
@@ -696,6 +1391,28 @@ pub fn convert_process_data(
                 process_entry.process_description_prefix = None;
                 process_entry.is_disabled_entry = false;
                 process_entry.user = user;
+                process_entry.read_chars = process.read_chars;
+                process_entry.write_chars = process.write_chars;
+                process_entry.read_syscalls = process.read_syscalls;
+                process_entry.write_syscalls = process.write_syscalls;
+                process_entry.cancelled_write_bytes = process.cancelled_write_bytes;
+                process_entry.cpus_allowed_list = process.cpus_allowed_list.clone();
+                process_entry.last_cpu = process.last_cpu;
+                process_entry.oom_score = process.oom_score;
+                process_entry.oom_score_adj = process.oom_score_adj;
+                process_entry.unit = process.unit.clone();
+                process_entry.is_kernel_thread = process.is_kernel_thread;
+                process_entry.minor_faults_per_sec = process.minor_faults_per_sec;
+                process_entry.major_faults_per_sec = process.major_faults_per_sec;
+                process_entry.voluntary_switches_per_sec = process.voluntary_switches_per_sec;
+                process_entry.involuntary_switches_per_sec = process.involuntary_switches_per_sec;
+                process_entry.fd_count = process.fd_count;
+                process_entry.fd_percent = get_fd_percent(process);
+                process_entry.is_exe_deleted = process.is_exe_deleted;
+                process_entry.has_deleted_libraries = process.has_deleted_libraries;
+                process_entry.tty = process.tty.clone();
+                process_entry.session_id = process.session_id;
+                process_entry.process_group_id = process.process_group_id;
             } else {
                 // ...I hate that I can't combine if let and an if statement in one line...
                 *process_entry = ConvertedProcessData {
@@ -723,6 +1440,28 @@ pub fn convert_process_data(
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
                     user,
+                    read_chars: process.read_chars,
+                    write_chars: process.write_chars,
+                    read_syscalls: process.read_syscalls,
+                    write_syscalls: process.write_syscalls,
+                    cancelled_write_bytes: process.cancelled_write_bytes,
+                    cpus_allowed_list: process.cpus_allowed_list.clone(),
+                    last_cpu: process.last_cpu,
+                    oom_score: process.oom_score,
+                    oom_score_adj: process.oom_score_adj,
+                    unit: process.unit.clone(),
+                    is_kernel_thread: process.is_kernel_thread,
+                    minor_faults_per_sec: process.minor_faults_per_sec,
+                    major_faults_per_sec: process.major_faults_per_sec,
+                    voluntary_switches_per_sec: process.voluntary_switches_per_sec,
+                    involuntary_switches_per_sec: process.involuntary_switches_per_sec,
+                    fd_count: process.fd_count,
+                    fd_percent: get_fd_percent(process),
+                    is_exe_deleted: process.is_exe_deleted,
+                    has_deleted_libraries: process.has_deleted_libraries,
+                    tty: process.tty.clone(),
+                    session_id: process.session_id,
+                    process_group_id: process.process_group_id,
                 };
             }
         } else {
@@ -753,6 +1492,28 @@ pub fn convert_process_data(
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
                     user,
+                    read_chars: process.read_chars,
+                    write_chars: process.write_chars,
+                    read_syscalls: process.read_syscalls,
+                    write_syscalls: process.write_syscalls,
+                    cancelled_write_bytes: process.cancelled_write_bytes,
+                    cpus_allowed_list: process.cpus_allowed_list.clone(),
+                    last_cpu: process.last_cpu,
+                    oom_score: process.oom_score,
+                    oom_score_adj: process.oom_score_adj,
+                    unit: process.unit.clone(),
+                    is_kernel_thread: process.is_kernel_thread,
+                    minor_faults_per_sec: process.minor_faults_per_sec,
+                    major_faults_per_sec: process.major_faults_per_sec,
+                    voluntary_switches_per_sec: process.voluntary_switches_per_sec,
+                    involuntary_switches_per_sec: process.involuntary_switches_per_sec,
+                    fd_count: process.fd_count,
+                    fd_percent: get_fd_percent(process),
+                    is_exe_deleted: process.is_exe_deleted,
+                    has_deleted_libraries: process.has_deleted_libraries,
+                    tty: process.tty.clone(),
+                    session_id: process.session_id,
+                    process_group_id: process.process_group_id,
                 },
             );
         }
@@ -769,6 +1530,65 @@ const BRANCH_VERTICAL: char = '│';
 const BRANCH_SPLIT: char = '├';
 const BRANCH_HORIZONTAL: char = '─';
 
+/// A DFS traversal to correctly build the prefix lines (the pretty '├' and '─' lines) and
+/// the correct order to a tree (such as the PID tree) as a vector.
+///
+/// Note that children are visited in *reverse* order.  Keys in `collapsed_set` are drawn, but their
+/// children are not.
+fn build_explored_tree<K: Clone + Eq + Hash>(
+    current_key: &K, parent_child_mapping: &HashMap<K, IndexSet<K, FxBuildHasher>>,
+    prev_drawn_lines: &str, collapsed_set: Option<&IndexSet<K, FxBuildHasher>>,
+) -> (Vec<K>, Vec<String>) {
+    let mut explored_keys: Vec<K> = vec![current_key.clone()];
+    let mut lines: Vec<String> = vec![];
+
+    if collapsed_set.map_or(false, |collapsed_set| collapsed_set.contains(current_key)) {
+        return (explored_keys, lines);
+    } else if let Some(children) = parent_child_mapping.get(current_key) {
+        for (itx, child) in children.iter().rev().enumerate() {
+            let new_drawn_lines = if itx == children.len() - 1 {
+                format!("{}   ", prev_drawn_lines)
+            } else {
+                format!("{}{}  ", prev_drawn_lines, BRANCH_VERTICAL)
+            };
+
+            let (key_res, branch_res) = build_explored_tree(
+                child,
+                parent_child_mapping,
+                new_drawn_lines.as_str(),
+                collapsed_set,
+            );
+
+            if itx == children.len() - 1 {
+                lines.push(format!(
+                    "{}{}",
+                    prev_drawn_lines,
+                    if !new_drawn_lines.is_empty() {
+                        format!("{}{} ", BRANCH_ENDING, BRANCH_HORIZONTAL)
+                    } else {
+                        String::default()
+                    }
+                ));
+            } else {
+                lines.push(format!(
+                    "{}{}",
+                    prev_drawn_lines,
+                    if !new_drawn_lines.is_empty() {
+                        format!("{}{} ", BRANCH_SPLIT, BRANCH_HORIZONTAL)
+                    } else {
+                        String::default()
+                    }
+                ));
+            }
+
+            explored_keys.extend(key_res);
+            lines.extend(branch_res);
+        }
+    }
+
+    (explored_keys, lines)
+}
+
 pub fn tree_process_data(
     filtered_process_data: &[ConvertedProcessData], is_using_command: bool,
     sorting_type: &ProcessSorting, is_sort_descending: bool,
@@ -1012,65 +1832,120 @@ pub fn tree_process_data(
             ProcessSorting::Count => {
                 // Should never occur in this case, tree mode explicitly disables grouping.
             }
+            ProcessSorting::ReadChars => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.read_chars, b.1.read_chars, is_sort_descending)
+            }),
+            ProcessSorting::WriteChars => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.write_chars, b.1.write_chars, is_sort_descending)
+            }),
+            ProcessSorting::ReadSyscalls => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.read_syscalls,
+                    b.1.read_syscalls,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::WriteSyscalls => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.write_syscalls,
+                    b.1.write_syscalls,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::CancelledWrite => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.cancelled_write_bytes,
+                    b.1.cancelled_write_bytes,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::Affinity => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    &a.1.cpus_allowed_list,
+                    &b.1.cpus_allowed_list,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::LastCpu => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.last_cpu, b.1.last_cpu, is_sort_descending)
+            }),
+            ProcessSorting::OomScore => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.oom_score, b.1.oom_score, is_sort_descending)
+            }),
+            ProcessSorting::OomScoreAdj => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.oom_score_adj,
+                    b.1.oom_score_adj,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::Unit => to_sort_vec.sort_by(|a, b| match (&a.1.unit, &b.1.unit) {
+                (Some(unit_a), Some(unit_b)) => utils::gen_util::get_ordering(
+                    unit_a.to_lowercase(),
+                    unit_b.to_lowercase(),
+                    is_sort_descending,
+                ),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Less,
+            }),
+            ProcessSorting::MinorFaults => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.minor_faults_per_sec,
+                    b.1.minor_faults_per_sec,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::MajorFaults => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.major_faults_per_sec,
+                    b.1.major_faults_per_sec,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::VoluntarySwitches => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.voluntary_switches_per_sec,
+                    b.1.voluntary_switches_per_sec,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::InvoluntarySwitches => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.involuntary_switches_per_sec,
+                    b.1.involuntary_switches_per_sec,
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::FdCount => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.fd_count, b.1.fd_count, is_sort_descending)
+            }),
+            ProcessSorting::FdPercent => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.fd_percent, b.1.fd_percent, is_sort_descending)
+            }),
+            ProcessSorting::DeletedCode => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    (a.1.is_exe_deleted, a.1.has_deleted_libraries),
+                    (b.1.is_exe_deleted, b.1.has_deleted_libraries),
+                    is_sort_descending,
+                )
+            }),
+            ProcessSorting::Tty => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(&a.1.tty, &b.1.tty, is_sort_descending)
+            }),
+            ProcessSorting::SessionId => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(a.1.session_id, b.1.session_id, is_sort_descending)
+            }),
+            ProcessSorting::ProcessGroupId => to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.1.process_group_id,
+                    b.1.process_group_id,
+                    is_sort_descending,
+                )
+            }),
         }
     }
 
-    /// A DFS traversal to correctly build the prefix lines (the pretty '├' and '─' lines) and
-    /// the correct order to the PID tree as a vector.
-    fn build_explored_pids(
-        current_pid: Pid, parent_child_mapping: &HashMap<Pid, IndexSet<Pid, FxBuildHasher>>,
-        prev_drawn_lines: &str, collapsed_set: &IndexSet<Pid, FxBuildHasher>,
-    ) -> (Vec<Pid>, Vec<String>) {
-        let mut explored_pids: Vec<Pid> = vec![current_pid];
-        let mut lines: Vec<String> = vec![];
-
-        if collapsed_set.contains(&current_pid) {
-            return (explored_pids, lines);
-        } else if let Some(children) = parent_child_mapping.get(&current_pid) {
-            for (itx, child) in children.iter().rev().enumerate() {
-                let new_drawn_lines = if itx == children.len() - 1 {
-                    format!("{}   ", prev_drawn_lines)
-                } else {
-                    format!("{}{}  ", prev_drawn_lines, BRANCH_VERTICAL)
-                };
-
-                let (pid_res, branch_res) = build_explored_pids(
-                    *child,
-                    parent_child_mapping,
-                    new_drawn_lines.as_str(),
-                    collapsed_set,
-                );
-
-                if itx == children.len() - 1 {
-                    lines.push(format!(
-                        "{}{}",
-                        prev_drawn_lines,
-                        if !new_drawn_lines.is_empty() {
-                            format!("{}{} ", BRANCH_ENDING, BRANCH_HORIZONTAL)
-                        } else {
-                            String::default()
-                        }
-                    ));
-                } else {
-                    lines.push(format!(
-                        "{}{}",
-                        prev_drawn_lines,
-                        if !new_drawn_lines.is_empty() {
-                            format!("{}{} ", BRANCH_SPLIT, BRANCH_HORIZONTAL)
-                        } else {
-                            String::default()
-                        }
-                    ));
-                }
-
-                explored_pids.extend(pid_res);
-                lines.extend(branch_res);
-            }
-        }
-
-        (explored_pids, lines)
-    }
-
     /// Returns the total sum of CPU, MEM%, MEM, R/s, W/s, Total Read, and Total Write via DFS traversal.
     fn get_usage_of_all_children(
         parent_pid: Pid, parent_child_mapping: &HashMap<Pid, IndexSet<Pid, FxBuildHasher>>,
@@ -1146,8 +2021,12 @@ pub fn tree_process_data(
                 &pid_process_mapping,
             );
 
-            let (pid_res, branch_res) =
-                build_explored_pids(current_pid, &parent_child_mapping, "", &collapsed_set);
+            let (pid_res, branch_res) = build_explored_tree(
+                &current_pid,
+                &parent_child_mapping,
+                "",
+                Some(&collapsed_set),
+            );
             lines.push(String::default());
             lines.extend(branch_res);
             explored_pids.extend(pid_res);
@@ -1244,76 +2123,178 @@ pub fn stringify_process_data(
     let is_using_command = proc_widget_state.is_using_command;
     let is_tree = proc_widget_state.is_tree_mode;
     let mem_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::Mem);
+    #[cfg(target_family = "unix")]
+    let user_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::User);
+    let state_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::State);
+    let optional_columns = proc_widget_state.columns.get_enabled_optional_columns();
 
     finalized_process_data
         .iter()
         .map(|process| {
-            (
-                vec![
-                    (
-                        if is_proc_widget_grouped {
-                            process.group_pids.len().to_string()
-                        } else {
-                            process.pid.to_string()
-                        },
-                        None,
-                    ),
-                    (
-                        if is_tree {
-                            if let Some(prefix) = &process.process_description_prefix {
-                                prefix.clone()
-                            } else {
-                                String::default()
-                            }
-                        } else if is_using_command {
-                            process.command.clone()
-                        } else {
-                            process.name.clone()
-                        },
-                        None,
-                    ),
-                    (format!("{:.1}%", process.cpu_percent_usage), None),
-                    (
-                        if mem_enabled {
-                            if process.mem_usage_bytes <= GIBI_LIMIT {
-                                format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                            } else {
-                                format!("{:.1}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                            }
+            let mut row = vec![
+                (
+                    if is_proc_widget_grouped {
+                        process.group_pids.len().to_string()
+                    } else {
+                        process.pid.to_string()
+                    },
+                    None,
+                ),
+                (
+                    if is_tree {
+                        if let Some(prefix) = &process.process_description_prefix {
+                            prefix.clone()
                         } else {
-                            format!("{:.1}%", process.mem_percent_usage)
-                        },
-                        None,
-                    ),
-                    (process.read_per_sec.clone(), None),
-                    (process.write_per_sec.clone(), None),
-                    (process.total_read.clone(), None),
-                    (process.total_write.clone(), None),
-                    #[cfg(target_family = "unix")]
-                    (
-                        if let Some(user) = &process.user {
-                            user.clone()
+                            String::default()
+                        }
+                    } else if is_using_command {
+                        process.command.clone()
+                    } else {
+                        process.name.clone()
+                    },
+                    None,
+                ),
+                (format!("{:.1}%", process.cpu_percent_usage), None),
+                (
+                    if mem_enabled {
+                        if process.mem_usage_bytes <= GIBI_LIMIT {
+                            format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
                         } else {
-                            "N/A".to_string()
-                        },
-                        None,
-                    ),
-                    (
-                        process.process_state.clone(),
-                        Some(process.process_char.to_string()),
-                    ),
-                ],
-                process.is_disabled_entry,
-            )
+                            format!("{:.1}{}", process.mem_usage_str.0, process.mem_usage_str.1)
+                        }
+                    } else {
+                        format!("{:.1}%", process.mem_percent_usage)
+                    },
+                    None,
+                ),
+                (process.read_per_sec.clone(), None),
+                (process.write_per_sec.clone(), None),
+                (process.total_read.clone(), None),
+                (process.total_write.clone(), None),
+            ];
+
+            #[cfg(target_family = "unix")]
+            if user_enabled {
+                row.push((
+                    if let Some(user) = &process.user {
+                        user.clone()
+                    } else {
+                        "N/A".to_string()
+                    },
+                    None,
+                ));
+            }
+            if state_enabled {
+                row.push((
+                    process.process_state.clone(),
+                    Some(process.process_char.to_string()),
+                ));
+            }
+            row.extend(
+                optional_columns
+                    .iter()
+                    .map(|column| (get_optional_column_string(process, column), None)),
+            );
+
+            (row, process.is_disabled_entry)
         })
         .collect()
 }
 
-/// Takes a set of converted process data and groups it together.
+/// Returns how many file descriptors a process has open as a percentage of its soft limit.
+fn get_fd_percent(process: &data_harvester::processes::ProcessHarvest) -> Option<f64> {
+    match (process.fd_count, process.fd_limit) {
+        (Some(fd_count), Some(fd_limit)) if fd_limit > 0 => {
+            Some(fd_count as f64 / fd_limit as f64 * 100.0)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the displayed value of an optional process column.
+fn get_optional_column_string(process: &ConvertedProcessData, column: &ProcessSorting) -> String {
+    let get_bytes_string = |bytes: u64| {
+        let converted_bytes = get_decimal_bytes(bytes);
+        if bytes >= GIGA_LIMIT {
+            format!("{:.1}{}", converted_bytes.0, converted_bytes.1)
+        } else {
+            format!("{:.0}{}", converted_bytes.0, converted_bytes.1)
+        }
+    };
+
+    match column {
+        ProcessSorting::ReadChars => get_bytes_string(process.read_chars),
+        ProcessSorting::WriteChars => get_bytes_string(process.write_chars),
+        ProcessSorting::ReadSyscalls => get_packet_count_string(process.read_syscalls),
+        ProcessSorting::WriteSyscalls => get_packet_count_string(process.write_syscalls),
+        ProcessSorting::CancelledWrite => get_bytes_string(process.cancelled_write_bytes),
+        ProcessSorting::Affinity => {
+            if process.cpus_allowed_list.is_empty() {
+                "N/A".to_string()
+            } else {
+                process.cpus_allowed_list.clone()
+            }
+        }
+        ProcessSorting::LastCpu => match process.last_cpu {
+            Some(last_cpu) => last_cpu.to_string(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::OomScore => match process.oom_score {
+            Some(oom_score) => oom_score.to_string(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::OomScoreAdj => match process.oom_score_adj {
+            Some(oom_score_adj) => oom_score_adj.to_string(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::Unit => match &process.unit {
+            Some(unit) => unit.clone(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::MinorFaults => get_packet_rate_string(process.minor_faults_per_sec),
+        ProcessSorting::MajorFaults => get_packet_rate_string(process.major_faults_per_sec),
+        ProcessSorting::VoluntarySwitches => {
+            get_packet_rate_string(process.voluntary_switches_per_sec)
+        }
+        ProcessSorting::InvoluntarySwitches => {
+            get_packet_rate_string(process.involuntary_switches_per_sec)
+        }
+        ProcessSorting::FdCount => match process.fd_count {
+            Some(fd_count) => fd_count.to_string(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::FdPercent => match process.fd_percent {
+            Some(fd_percent) => format!("{:.1}%", fd_percent),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::DeletedCode => {
+            match (process.is_exe_deleted, process.has_deleted_libraries) {
+                (true, true) => "exe+lib",
+                (true, false) => "exe",
+                (false, true) => "lib",
+                (false, false) => "",
+            }
+            .to_string()
+        }
+        ProcessSorting::Tty => process.tty.clone().unwrap_or_else(|| "?".to_string()),
+        ProcessSorting::SessionId => match process.session_id {
+            Some(session_id) => session_id.to_string(),
+            None => "N/A".to_string(),
+        },
+        ProcessSorting::ProcessGroupId => match process.process_group_id {
+            Some(process_group_id) => process_group_id.to_string(),
+            None => "N/A".to_string(),
+        },
+        _ => String::default(),
+    }
+}
+
+/// Takes a set of converted process data and groups it together, either by name (or command) or by
+/// systemd unit.
 ///
 /// To be honest, I really don't like how this is done, even though I've rewritten this like 3 times.
 pub fn group_process_data(
-    single_process_data: &[ConvertedProcessData], is_using_command: bool,
+    single_process_data: &[ConvertedProcessData], is_using_command: bool, is_grouped_by_unit: bool,
 ) -> Vec<ConvertedProcessData> {
     #[derive(Clone, Default, Debug)]
     struct SingleProcessData {
@@ -1327,21 +2308,88 @@ pub fn group_process_data(
         pub total_read: f64,
         pub total_write: f64,
         pub process_state: String,
+        pub process_char: char,
+        pub is_stopped: bool,
+        pub read_chars: u64,
+        pub write_chars: u64,
+        pub read_syscalls: u64,
+        pub write_syscalls: u64,
+        pub cancelled_write_bytes: u64,
+        pub cpus_allowed_list: String,
+        pub oom_score: Option<u32>,
+        pub oom_score_adj: Option<i32>,
+        pub unit: Option<String>,
+        pub is_kernel_thread: bool,
+        pub minor_faults_per_sec: f64,
+        pub major_faults_per_sec: f64,
+        pub voluntary_switches_per_sec: f64,
+        pub involuntary_switches_per_sec: f64,
+        pub fd_count: Option<u64>,
+        pub fd_percent: Option<f64>,
+        pub is_exe_deleted: bool,
+        pub has_deleted_libraries: bool,
+        pub tty: Option<String>,
+        pub session_id: Option<Pid>,
+        pub process_group_id: Option<Pid>,
     }
 
-    let mut grouped_hashmap: HashMap<String, SingleProcessData> = std::collections::HashMap::new();
+    // Keyed by whether the group is a unit, so a unit can't collide with a process of the same name.
+    let mut grouped_hashmap: HashMap<(bool, String), SingleProcessData> =
+        std::collections::HashMap::new();
 
     single_process_data.iter().for_each(|process| {
-        let entry = grouped_hashmap
-            .entry(if is_using_command {
-                process.command.to_string()
-            } else {
-                process.name.to_string()
-            })
-            .or_insert(SingleProcessData {
-                pid: process.pid,
-                ..SingleProcessData::default()
-            });
+        // Processes outside of any unit are grouped by their name instead.
+        let key = match &process.unit {
+            Some(unit) if is_grouped_by_unit => (true, unit.clone()),
+            _ if is_using_command => (false, process.command.to_string()),
+            _ => (false, process.name.to_string()),
+        };
+        let entry = grouped_hashmap.entry(key).or_insert(SingleProcessData {
+            pid: process.pid,
+            ..SingleProcessData::default()
+        });
+
+        // Only show an affinity or unit for the group if every member shares it, and likewise for
+        // whether the group is made up of kernel threads.
+        if (*entry).group_pids.is_empty() {
+            (*entry).cpus_allowed_list = process.cpus_allowed_list.clone();
+            (*entry).unit = process.unit.clone();
+            (*entry).is_kernel_thread = process.is_kernel_thread;
+            (*entry).tty = process.tty.clone();
+            (*entry).session_id = process.session_id;
+            (*entry).process_group_id = process.process_group_id;
+            (*entry).is_stopped = process.process_char == 'T';
+        } else {
+            if (*entry).cpus_allowed_list != process.cpus_allowed_list {
+                (*entry).cpus_allowed_list = "*".to_string();
+            }
+            if (*entry).unit != process.unit {
+                (*entry).unit = Some("*".to_string());
+            }
+            (*entry).is_kernel_thread &= process.is_kernel_thread;
+            if (*entry).tty != process.tty {
+                (*entry).tty = Some("*".to_string());
+            }
+            if (*entry).session_id != process.session_id {
+                (*entry).session_id = None;
+            }
+            if (*entry).process_group_id != process.process_group_id {
+                (*entry).process_group_id = None;
+            }
+            (*entry).is_stopped &= process.process_char == 'T';
+        }
+
+        // Flag the group if any member is a zombie or in uninterruptible sleep, preferring zombies.
+        // A group only counts as stopped if every member is, which is handled below.
+        match process.process_char {
+            'Z' => (*entry).process_char = 'Z',
+            'D' if (*entry).process_char != 'Z' => (*entry).process_char = 'D',
+            _ => {}
+        }
+
+        // A group is as likely to be OOM killed as its most likely member.
+        (*entry).oom_score = (*entry).oom_score.max(process.oom_score);
+        (*entry).oom_score_adj = (*entry).oom_score_adj.max(process.oom_score_adj);
 
         (*entry).cpu_percent_usage += process.cpu_percent_usage;
         (*entry).mem_percent_usage += process.mem_percent_usage;
@@ -1351,11 +2399,31 @@ pub fn group_process_data(
         (*entry).write_per_sec += process.wps_f64;
         (*entry).total_read += process.tr_f64;
         (*entry).total_write += process.tw_f64;
+        (*entry).read_chars += process.read_chars;
+        (*entry).write_chars += process.write_chars;
+        (*entry).read_syscalls += process.read_syscalls;
+        (*entry).write_syscalls += process.write_syscalls;
+        (*entry).cancelled_write_bytes += process.cancelled_write_bytes;
+        (*entry).minor_faults_per_sec += process.minor_faults_per_sec;
+        (*entry).major_faults_per_sec += process.major_faults_per_sec;
+        (*entry).voluntary_switches_per_sec += process.voluntary_switches_per_sec;
+        (*entry).involuntary_switches_per_sec += process.involuntary_switches_per_sec;
+        (*entry).is_exe_deleted |= process.is_exe_deleted;
+        (*entry).has_deleted_libraries |= process.has_deleted_libraries;
+        (*entry).fd_count = match ((*entry).fd_count, process.fd_count) {
+            (Some(group_fd_count), Some(fd_count)) => Some(group_fd_count + fd_count),
+            (group_fd_count, fd_count) => group_fd_count.or(fd_count),
+        };
+
+        // Limits are per process, so show how close the worst member is to its own.
+        if process.fd_percent > (*entry).fd_percent {
+            (*entry).fd_percent = process.fd_percent;
+        }
     });
 
     grouped_hashmap
         .iter()
-        .map(|(identifier, process_details)| {
+        .map(|((_is_unit, identifier), process_details)| {
             let p = process_details.clone();
 
             let (read_per_sec, write_per_sec, total_read, total_write) = get_disk_io_strings(
@@ -1386,10 +2454,32 @@ pub fn group_process_data(
                 tw_f64: p.total_write,
                 process_state: p.process_state,
                 process_description_prefix: None,
-                process_char: char::default(),
+                process_char: if p.is_stopped { 'T' } else { p.process_char },
                 is_disabled_entry: false,
                 is_collapsed_entry: false,
                 user: None,
+                read_chars: p.read_chars,
+                write_chars: p.write_chars,
+                read_syscalls: p.read_syscalls,
+                write_syscalls: p.write_syscalls,
+                cancelled_write_bytes: p.cancelled_write_bytes,
+                cpus_allowed_list: p.cpus_allowed_list,
+                last_cpu: None,
+                oom_score: p.oom_score,
+                oom_score_adj: p.oom_score_adj,
+                unit: p.unit,
+                is_kernel_thread: p.is_kernel_thread,
+                minor_faults_per_sec: p.minor_faults_per_sec,
+                major_faults_per_sec: p.major_faults_per_sec,
+                voluntary_switches_per_sec: p.voluntary_switches_per_sec,
+                involuntary_switches_per_sec: p.involuntary_switches_per_sec,
+                fd_count: p.fd_count,
+                fd_percent: p.fd_percent,
+                is_exe_deleted: p.is_exe_deleted,
+                has_deleted_libraries: p.has_deleted_libraries,
+                tty: p.tty,
+                session_id: p.session_id,
+                process_group_id: p.process_group_id,
             }
         })
         .collect::<Vec<_>>()
@@ -1440,6 +2530,201 @@ pub fn convert_battery_harvest(
                 None
             },
             health: format!("{:.2}%", battery_harvest.health_percent),
+            state: capitalize_first(&battery_harvest.state),
+            cycle_count: battery_harvest
+                .cycle_count
+                .map_or_else(|| "N/A".to_string(), |cycle_count| cycle_count.to_string()),
+            energy_full: format!("{:.2}Wh", battery_harvest.energy_full_wh),
+            energy_full_design: format!("{:.2}Wh", battery_harvest.energy_full_design_wh),
+            voltage: format!("{:.2}V", battery_harvest.voltage_volts),
+            technology: capitalize_first(&battery_harvest.technology),
+            vendor: get_non_empty_or_na(&battery_harvest.vendor),
+            model: get_non_empty_or_na(&battery_harvest.model),
+            charge_data: get_battery_data_points(current_data, itx, |(charge, _)| charge),
+            consumption_data: get_battery_data_points(current_data, itx, |(_, watts)| watts),
         })
         .collect()
 }
+
+#[cfg(feature = "battery")]
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::default(),
+    }
+}
+
+/// Formats an uptime like `3d 04:12:33`, like `top` and `htop` do.
+fn get_uptime_string(secs: u64) -> String {
+    let (days, hours, minutes, seconds) = (
+        secs / 86400,
+        (secs % 86400) / 3600,
+        (secs % 3600) / 60,
+        secs % 60,
+    );
+
+    match days {
+        0 => format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
+        1 => format!("1 day, {:02}:{:02}:{:02}", hours, minutes, seconds),
+        _ => format!("{} days, {:02}:{:02}:{:02}", days, hours, minutes, seconds),
+    }
+}
+
+pub fn convert_summary_data(current_data: &data_farmer::DataCollection) -> ConvertedSummaryData {
+    let system = &current_data.system_harvest;
+    let tasks = &system.tasks;
+    let load_avg = &current_data.load_avg_harvest;
+
+    ConvertedSummaryData {
+        hostname: system.hostname.clone(),
+        kernel_version: system.kernel_version.clone(),
+        uptime: get_uptime_string(system.uptime_secs),
+        user_count: system.user_count.to_string(),
+        tasks: format!(
+            "{} total, {} running, {} sleeping, {} stopped, {} zombie",
+            tasks.total, tasks.running, tasks.sleeping, tasks.stopped, tasks.zombie
+        ),
+        load_avg: if cfg!(target_family = "unix") {
+            format!("{:.2} {:.2} {:.2}", load_avg[0], load_avg[1], load_avg[2])
+        } else {
+            "N/A".to_string()
+        },
+        file_descriptors: match system.file_descriptors {
+            // Most systems default to a maximum of LONG_MAX, which is effectively unlimited.
+            Some((open, max)) if max > 0 && max < i64::MAX as u64 => format!(
+                "{} of {} ({:.1}%)",
+                open,
+                max,
+                open as f64 / max as f64 * 100.0
+            ),
+            Some((open, _max)) => open.to_string(),
+            None => "N/A".to_string(),
+        },
+        needs_restart: if cfg!(target_os = "linux") {
+            let count = current_data
+                .process_harvest
+                .iter()
+                .filter(|process| process.is_exe_deleted || process.has_deleted_libraries)
+                .count();
+            format!(
+                "{} {}",
+                count,
+                if count == 1 { "process" } else { "processes" }
+            )
+        } else {
+            "N/A".to_string()
+        },
+    }
+}
+
+#[cfg(feature = "battery")]
+fn get_non_empty_or_na(text: &Option<String>) -> String {
+    match text {
+        Some(text) if !text.is_empty() => text.clone(),
+        _ => "N/A".to_string(),
+    }
+}
+
+/// Returns the history of one value of a battery, as selected by `get_value`.
+#[cfg(feature = "battery")]
+fn get_battery_data_points(
+    current_data: &data_farmer::DataCollection, battery_index: usize,
+    get_value: impl Fn((f64, f64)) -> f64,
+) -> Vec<Point> {
+    let current_time = current_data.current_instant;
+
+    current_data
+        .timed_data_vec
+        .iter()
+        .filter_map(|(time, data)| {
+            let battery_data = data.battery_data.get(battery_index)?;
+            let time_from_start: f64 =
+                (current_time.duration_since(*time).as_millis() as f64).floor();
+
+            Some((-time_from_start, get_value(*battery_data)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_process(pid: Pid, name: &str, process_char: char) -> ConvertedProcessData {
+        ConvertedProcessData {
+            pid,
+            name: name.to_string(),
+            process_char,
+            ..Default::default()
+        }
+    }
+
+    fn get_group_char(grouped: &[ConvertedProcessData], name: &str) -> char {
+        grouped
+            .iter()
+            .find(|process| process.name == name)
+            .unwrap()
+            .process_char
+    }
+
+    #[test]
+    fn test_group_process_char() {
+        let processes = vec![
+            get_process(1, "healthy", 'S'),
+            get_process(2, "healthy", 'R'),
+            get_process(3, "uninterruptible", 'S'),
+            get_process(4, "uninterruptible", 'D'),
+            get_process(5, "zombie", 'D'),
+            get_process(6, "zombie", 'Z'),
+            get_process(7, "zombie", 'D'),
+            get_process(8, "stopped", 'T'),
+            get_process(9, "stopped", 'T'),
+            get_process(10, "partly_stopped", 'T'),
+            get_process(11, "partly_stopped", 'S'),
+        ];
+        let grouped = group_process_data(&processes, false, false);
+
+        assert_eq!(get_group_char(&grouped, "healthy"), char::default());
+        assert_eq!(get_group_char(&grouped, "uninterruptible"), 'D');
+        assert_eq!(get_group_char(&grouped, "zombie"), 'Z');
+        assert_eq!(get_group_char(&grouped, "stopped"), 'T');
+        assert_eq!(get_group_char(&grouped, "partly_stopped"), char::default());
+    }
+
+    #[test]
+    fn test_group_by_unit_without_unit() {
+        let get_unit_process = |pid, name: &str, unit: Option<&str>| ConvertedProcessData {
+            unit: unit.map(|unit| unit.to_string()),
+            ..get_process(pid, name, 'S')
+        };
+        let processes = vec![
+            get_unit_process(1, "sshd", Some("sshd.service")),
+            get_unit_process(2, "sshd", Some("sshd.service")),
+            get_unit_process(3, "kworker", None),
+            get_unit_process(4, "kworker", None),
+            get_unit_process(5, "init", None),
+            get_unit_process(6, "sshd.service", None),
+        ];
+        let grouped = group_process_data(&processes, false, true);
+
+        let mut groups = grouped
+            .iter()
+            .map(|process| {
+                let mut group_pids = process.group_pids.clone();
+                group_pids.sort_unstable();
+                (process.name.as_str(), group_pids)
+            })
+            .collect::<Vec<_>>();
+        groups.sort();
+        assert_eq!(
+            groups,
+            vec![
+                ("init", vec![5]),
+                ("kworker", vec![3, 4]),
+                ("sshd.service", vec![1, 2]),
+                ("sshd.service", vec![6]),
+            ]
+        );
+    }
+}
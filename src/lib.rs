@@ -82,7 +82,11 @@ pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
                         // Trigger left click widget activity
                         app.on_left_mouse_up(x, y);
                     }
-                    crossterm::event::MouseButton::Right => {}
+                    crossterm::event::MouseButton::Right => {
+                        // Suspend or resume the clicked process
+                        #[cfg(target_family = "unix")]
+                        app.on_right_mouse_up(x, y);
+                    }
                     _ => {}
                 }
             }
@@ -103,7 +107,10 @@ pub fn handle_key_event_or_break(
 
     if event.modifiers.is_empty() {
         // Required catch for searching - otherwise you couldn't search with q.
-        if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
+        if event.code == KeyCode::Char('q')
+            && !app.is_in_search_widget()
+            && !app.files_dialog_state.is_showing_files
+        {
             return true;
         }
         match event.code {
@@ -331,6 +338,21 @@ pub fn handle_force_redraws(app: &mut App) {
         app.mem_state.force_update = None;
     }
 
+    if app.load_avg_state.force_update.is_some() {
+        app.canvas_data.load_avg_points = convert_load_avg_data_points(
+            &app.data_collection,
+            app.is_frozen,
+            app.app_config_fields.normalize_load_avg,
+        );
+        app.load_avg_state.force_update = None;
+    }
+
+    if app.protocols_state.force_update.is_some() {
+        app.canvas_data.tcp_retransmit_data =
+            convert_tcp_retransmit_data_points(&app.data_collection, app.is_frozen);
+        app.protocols_state.force_update = None;
+    }
+
     if app.net_state.force_update.is_some() {
         let (rx, tx) = get_rx_tx_data_points(
             &app.data_collection,
@@ -341,6 +363,9 @@ pub fn handle_force_redraws(app: &mut App) {
         );
         app.canvas_data.network_data_rx = rx;
         app.canvas_data.network_data_tx = tx;
+        let (rx_packets, tx_packets) = get_packet_data_points(&app.data_collection, app.is_frozen);
+        app.canvas_data.network_data_rx_packets = rx_packets;
+        app.canvas_data.network_data_tx_packets = tx_packets;
         app.net_state.force_update = None;
     }
 }
@@ -376,11 +401,23 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                     .is_invalid_or_blank_search(),
                 process_state.is_using_command,
                 process_state.is_grouped,
+                process_state.is_grouped_by_unit,
                 process_state.is_tree_mode,
+                process_state.is_kernel_threads_hidden,
+                process_state.is_stuck_filter_enabled,
             )
         });
 
-    if let Some((is_invalid_or_blank, is_using_command, is_grouped, is_tree)) = process_states {
+    if let Some((
+        is_invalid_or_blank,
+        is_using_command,
+        is_grouped,
+        is_grouped_by_unit,
+        is_tree,
+        is_kernel_threads_hidden,
+        is_stuck_filter_enabled,
+    )) = process_states
+    {
         if !app.is_frozen {
             convert_process_data(
                 &app.data_collection,
@@ -390,10 +427,12 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
             );
         }
         let process_filter = app.get_process_filter(widget_id);
+        let is_stuck = |process: &ConvertedProcessData| matches!(process.process_char, 'Z' | 'D');
         let filtered_process_data: Vec<ConvertedProcessData> = if is_tree {
             app.canvas_data
                 .single_process_data
                 .iter()
+                .filter(|(_pid, process)| !(is_kernel_threads_hidden && process.is_kernel_thread))
                 .map(|(_pid, process)| {
                     let mut process_clone = process.clone();
                     if !is_invalid_or_blank {
@@ -402,6 +441,9 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                                 !process_filter.check(&process_clone, is_using_command);
                         }
                     }
+                    if is_stuck_filter_enabled && !is_stuck(&process_clone) {
+                        process_clone.is_disabled_entry = true;
+                    }
                     process_clone
                 })
                 .collect::<Vec<_>>()
@@ -409,6 +451,8 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
             app.canvas_data
                 .single_process_data
                 .iter()
+                .filter(|(_pid, process)| !(is_kernel_threads_hidden && process.is_kernel_thread))
+                .filter(|(_pid, process)| !is_stuck_filter_enabled || is_stuck(process))
                 .filter_map(|(_pid, process)| {
                     if !is_invalid_or_blank {
                         if let Some(process_filter) = process_filter {
@@ -437,7 +481,7 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                     proc_widget_state.is_process_sort_descending,
                 )
             } else if is_grouped {
-                group_process_data(&filtered_process_data, is_using_command)
+                group_process_data(&filtered_process_data, is_using_command, is_grouped_by_unit)
             } else {
                 filtered_process_data
             };
@@ -578,6 +622,149 @@ fn sort_process_data(
             (None, Some(_)) => std::cmp::Ordering::Greater,
             (None, None) => std::cmp::Ordering::Less,
         }),
+        ProcessSorting::ReadChars => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.read_chars,
+                b.read_chars,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::WriteChars => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.write_chars,
+                b.write_chars,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::ReadSyscalls => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.read_syscalls,
+                b.read_syscalls,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::WriteSyscalls => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.write_syscalls,
+                b.write_syscalls,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::CancelledWrite => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.cancelled_write_bytes,
+                b.cancelled_write_bytes,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::Affinity => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                &a.cpus_allowed_list,
+                &b.cpus_allowed_list,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::LastCpu => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.last_cpu,
+                b.last_cpu,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::OomScore => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.oom_score,
+                b.oom_score,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::OomScoreAdj => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.oom_score_adj,
+                b.oom_score_adj,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::Unit => to_sort_vec.sort_by(|a, b| match (&a.unit, &b.unit) {
+            (Some(unit_a), Some(unit_b)) => utils::gen_util::get_ordering(
+                unit_a.to_lowercase(),
+                unit_b.to_lowercase(),
+                proc_widget_state.is_process_sort_descending,
+            ),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Less,
+        }),
+        ProcessSorting::MinorFaults => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.minor_faults_per_sec,
+                b.minor_faults_per_sec,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::MajorFaults => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.major_faults_per_sec,
+                b.major_faults_per_sec,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::VoluntarySwitches => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.voluntary_switches_per_sec,
+                b.voluntary_switches_per_sec,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::InvoluntarySwitches => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.involuntary_switches_per_sec,
+                b.involuntary_switches_per_sec,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::FdCount => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.fd_count,
+                b.fd_count,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::FdPercent => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.fd_percent,
+                b.fd_percent,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::DeletedCode => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                (a.is_exe_deleted, a.has_deleted_libraries),
+                (b.is_exe_deleted, b.has_deleted_libraries),
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::Tty => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                &a.tty,
+                &b.tty,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::SessionId => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.session_id,
+                b.session_id,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
+        ProcessSorting::ProcessGroupId => to_sort_vec.sort_by(|a, b| {
+            utils::gen_util::get_ordering(
+                a.process_group_id,
+                b.process_group_id,
+                proc_widget_state.is_process_sort_descending,
+            )
+        }),
         ProcessSorting::Count => {
             if proc_widget_state.is_grouped {
                 to_sort_vec.sort_by(|a, b| {
@@ -647,6 +834,7 @@ pub fn create_collection_thread(
     let temp_type = app_config_fields.temperature_type.clone();
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let show_average_cpu = app_config_fields.show_average_cpu;
+    let get_all_partitions = app_config_fields.all_partitions;
     let update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
 
     thread::spawn(move || {
@@ -656,6 +844,7 @@ pub fn create_collection_thread(
         data_state.set_temperature_type(temp_type);
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_show_average_cpu(show_average_cpu);
+        data_state.set_get_all_partitions(get_all_partitions);
 
         data_state.init();
 
@@ -681,6 +870,7 @@ pub fn create_collection_thread(
                         data_state
                             .set_use_current_cpu_total(app_config_fields.use_current_cpu_total);
                         data_state.set_show_average_cpu(app_config_fields.show_average_cpu);
+                        data_state.set_get_all_partitions(app_config_fields.all_partitions);
                     }
                     ThreadControlEvent::UpdateUsedWidgets(used_widget_set) => {
                         data_state.set_collected_data(*used_widget_set);
@@ -10,7 +10,7 @@ use std::{
 };
 
 use crate::{
-    app::{layout_manager::*, *},
+    app::{data_harvester::processes::ProcessSorting, layout_manager::*, *},
     canvas::ColourScheme,
     constants::*,
     units::data_units::DataUnit,
@@ -32,6 +32,7 @@ pub struct Config {
     pub row: Option<Vec<Row>>,
     pub disk_filter: Option<IgnoreList>,
     pub mount_filter: Option<IgnoreList>,
+    pub fs_filter: Option<IgnoreList>,
     pub temp_filter: Option<IgnoreList>,
     pub net_filter: Option<IgnoreList>,
 }
@@ -162,6 +163,9 @@ pub struct ConfigFlags {
     #[builder(default, setter(strip_option))]
     pub disable_advanced_kill: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub hide_kernel_threads: Option<bool>,
+
     #[builder(default, setter(strip_option))]
     pub network_use_bytes: Option<bool>,
 
@@ -170,6 +174,24 @@ pub struct ConfigFlags {
 
     #[builder(default, setter(strip_option))]
     pub network_use_binary_prefix: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub all_partitions: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub disk_tree: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub disk_full_warning: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    pub normalize_load_avg: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub network_packet_graph: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub process_columns: Option<Vec<String>>,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -213,6 +235,11 @@ pub struct ConfigColours {
     pub high_battery_color: Option<String>,
     pub medium_battery_color: Option<String>,
     pub low_battery_color: Option<String>,
+    pub disk_full_warning_color: Option<String>,
+    pub zombie_process_color: Option<String>,
+    pub uninterruptible_process_color: Option<String>,
+    pub fd_warning_color: Option<String>,
+    pub stopped_process_color: Option<String>,
 }
 
 impl ConfigColours {
@@ -272,6 +299,10 @@ pub fn build_app(
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::new();
     let mut disk_state_map: HashMap<u64, DiskWidgetState> = HashMap::new();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::new();
+    let mut load_avg_state_map: HashMap<u64, LoadAvgWidgetState> = HashMap::new();
+    let mut net_interfaces_state_map: HashMap<u64, NetInterfacesWidgetState> = HashMap::new();
+    let mut protocols_state_map: HashMap<u64, ProtocolsWidgetState> = HashMap::new();
+    let mut sockets_state_map: HashMap<u64, SocketsWidgetState> = HashMap::new();
 
     let autohide_timer = if autohide_time {
         Some(Instant::now())
@@ -288,6 +319,10 @@ pub fn build_app(
     let is_default_tree = get_is_default_tree(matches, config);
     let is_default_command = get_is_default_process_command(matches, config);
     let is_advanced_kill = !get_is_advanced_kill_disabled(matches, config);
+    let is_kernel_threads_hidden = get_hide_kernel_threads(matches, config);
+    let is_default_disk_tree = get_disk_tree(matches, config);
+    let process_columns =
+        get_process_columns(config).context("Update 'process_columns' in your config file.")?;
 
     let network_unit_type = get_network_unit_type(matches, config);
     let network_scale_type = get_network_scale_type(matches, config);
@@ -366,18 +401,44 @@ pub fn build_app(
                                     show_memory_as_values,
                                     is_default_tree,
                                     is_default_command,
+                                    is_kernel_threads_hidden,
+                                    &process_columns,
                                 ),
                             );
                         }
                         Disk => {
-                            disk_state_map.insert(widget.widget_id, DiskWidgetState::init());
+                            disk_state_map.insert(
+                                widget.widget_id,
+                                DiskWidgetState::init(is_default_disk_tree),
+                            );
                         }
                         Temp => {
                             temp_state_map.insert(widget.widget_id, TempWidgetState::init());
                         }
                         Battery => {
-                            battery_state_map
-                                .insert(widget.widget_id, BatteryWidgetState::default());
+                            battery_state_map.insert(
+                                widget.widget_id,
+                                BatteryWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        LoadAvg => {
+                            load_avg_state_map.insert(
+                                widget.widget_id,
+                                LoadAvgWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        NetInterfaces => {
+                            net_interfaces_state_map
+                                .insert(widget.widget_id, NetInterfacesWidgetState::init());
+                        }
+                        Sockets => {
+                            sockets_state_map.insert(widget.widget_id, SocketsWidgetState::init());
+                        }
+                        Protocols => {
+                            protocols_state_map.insert(
+                                widget.widget_id,
+                                ProtocolsWidgetState::init(default_time_value, autohide_timer),
+                            );
                         }
                         _ => {}
                     }
@@ -438,6 +499,11 @@ pub fn build_app(
         no_write: false,
         show_table_scroll_position: get_show_table_scroll_position(matches, config),
         is_advanced_kill,
+        all_partitions: get_all_partitions(matches, config),
+        disk_full_warning: get_disk_full_warning(matches, config)
+            .context("Update 'disk_full_warning' in your config file.")?,
+        normalize_load_avg: get_normalize_load_avg(matches, config),
+        network_packet_graph: get_network_packet_graph(matches, config),
         network_scale_type,
         network_unit_type,
         network_use_binary_prefix,
@@ -451,12 +517,19 @@ pub fn build_app(
         use_disk: used_widget_set.get(&Disk).is_some(),
         use_temp: used_widget_set.get(&Temp).is_some(),
         use_battery: used_widget_set.get(&Battery).is_some(),
+        use_load_avg: used_widget_set.get(&LoadAvg).is_some(),
+        use_summary: used_widget_set.get(&Summary).is_some(),
+        use_net_interfaces: used_widget_set.get(&NetInterfaces).is_some(),
+        use_protocols: used_widget_set.get(&Protocols).is_some(),
+        use_sockets: used_widget_set.get(&Sockets).is_some(),
     };
 
     let disk_filter =
         get_ignore_list(&config.disk_filter).context("Update 'disk_filter' in your config file")?;
     let mount_filter = get_ignore_list(&config.mount_filter)
         .context("Update 'mount_filter' in your config file")?;
+    let fs_filter =
+        get_ignore_list(&config.fs_filter).context("Update 'fs_filter' in your config file")?;
     let temp_filter =
         get_ignore_list(&config.temp_filter).context("Update 'temp_filter' in your config file")?;
     let net_filter =
@@ -513,6 +586,10 @@ pub fn build_app(
         .disk_state(DiskState::init(disk_state_map))
         .temp_state(TempState::init(temp_state_map))
         .battery_state(BatteryState::init(battery_state_map))
+        .load_avg_state(LoadAvgState::init(load_avg_state_map))
+        .net_interfaces_state(NetInterfacesState::init(net_interfaces_state_map))
+        .protocols_state(ProtocolsState::init(protocols_state_map))
+        .sockets_state(SocketsState::init(sockets_state_map))
         .basic_table_widget_state(basic_table_widget_state)
         .current_widget(widget_map.get(&initial_widget_id).unwrap().clone()) // TODO: [UNWRAP] - many of the unwraps are fine (like this one) but do a once-over and/or switch to expect?
         .widget_map(widget_map)
@@ -520,6 +597,7 @@ pub fn build_app(
         .filters(DataFilters {
             disk_filter,
             mount_filter,
+            fs_filter,
             temp_filter,
             net_filter,
         })
@@ -1062,6 +1140,17 @@ fn get_is_default_process_command(matches: &clap::ArgMatches, config: &Config) -
     false
 }
 
+fn get_hide_kernel_threads(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("hide_kernel_threads") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(hide_kernel_threads) = flags.hide_kernel_threads {
+            return hide_kernel_threads;
+        }
+    }
+    false
+}
+
 fn get_is_advanced_kill_disabled(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("disable_advanced_kill") {
         return true;
@@ -1111,3 +1200,74 @@ fn get_network_use_binary_prefix(matches: &clap::ArgMatches, config: &Config) ->
     }
     false
 }
+
+fn get_all_partitions(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("all_partitions") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(all_partitions) = flags.all_partitions {
+            return all_partitions;
+        }
+    }
+    false
+}
+
+fn get_disk_tree(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("disk_tree") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(disk_tree) = flags.disk_tree {
+            return disk_tree;
+        }
+    }
+    false
+}
+
+fn get_disk_full_warning(matches: &clap::ArgMatches, config: &Config) -> error::Result<u64> {
+    if let Some(disk_full_warning) = matches.value_of("disk_full_warning") {
+        return disk_full_warning.parse::<u64>().map_err(|_| {
+            BottomError::ConfigError(
+                "could not parse as a valid 64-bit unsigned integer".to_string(),
+            )
+        });
+    } else if let Some(flags) = &config.flags {
+        if let Some(disk_full_warning) = flags.disk_full_warning {
+            return Ok(disk_full_warning);
+        }
+    }
+    Ok(DEFAULT_DISK_FULL_WARNING_SECONDS)
+}
+
+fn get_normalize_load_avg(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("normalize_load_avg") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(normalize_load_avg) = flags.normalize_load_avg {
+            return normalize_load_avg;
+        }
+    }
+    false
+}
+
+fn get_network_packet_graph(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("network_packet_graph") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(network_packet_graph) = flags.network_packet_graph {
+            return network_packet_graph;
+        }
+    }
+    false
+}
+
+fn get_process_columns(config: &Config) -> error::Result<Vec<ProcessSorting>> {
+    if let Some(flags) = &config.flags {
+        if let Some(process_columns) = &flags.process_columns {
+            return process_columns
+                .iter()
+                .map(|column| column.parse::<ProcessSorting>())
+                .collect();
+        }
+    }
+    Ok(vec![])
+}
@@ -115,6 +115,18 @@ fn test_invalid_rate() {
         .stderr(predicate::str::contains("could not parse"));
 }
 
+#[test]
+fn test_invalid_disk_full_warning() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/empty_config.toml")
+        .arg("--disk_full_warning")
+        .arg("1h")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("could not parse"));
+}
+
 #[test]
 fn test_conflicting_temps() {
     Command::new(get_binary_location())